@@ -1,27 +1,21 @@
 use crate::{
-    error::ErrorCode,
+    device::LedgerDevice,
     get_public_key::GetPublicKey,
     menu::{Menu, MenuAction, MenuPage},
     settings::Settings,
-    signer::{Scheme, Signer},
+    sign::Sign,
 };
 use ledger_device_sdk::{
     buttons::ButtonEvent,
     io::{ApduHeader, Comm},
 };
-
-const APDU_CLA: u8 = 0x89;
-
-const INS_GET_VERSION: u8 = 0x00;
-const INS_GET_PUBLIC_KEY: u8 = 0x01;
-const INS_SIGN: u8 = 0x02;
-const INS_QUIT: u8 = 0xFF;
-
-const COMMAND_START: u8 = 0x00;
-const COMMAND_APPEND: u8 = 0x01;
-const COMMAND_FINALIZE: u8 = 0x02;
-
-const MODE_INTERACTIVE: u8 = 0x01;
+use vara_core::{
+    apdu::*,
+    error::ErrorCode,
+    path::{self, Path},
+    signer::{Scheme, Signer},
+    ss58::Address,
+};
 
 /// Application struct.
 #[derive(Default)]
@@ -139,21 +133,26 @@ impl App {
                 comm.append(&patch.to_be_bytes());
             }
             INS_GET_PUBLIC_KEY => {
+                if header.p2 & !(MODE_INTERACTIVE | MODE_ADDRESS) != 0 {
+                    return Err(ErrorCode::BadP1P2);
+                }
                 self.signer.clear();
                 self.signer.set_scheme(header.p1.try_into()?);
                 self.signer.set_path(get_path(comm)?);
-                let key = self.signer.get_public_key()?;
-                if header.p2 == MODE_INTERACTIVE {
-                    let mut get_public_key = GetPublicKey::new(&key);
+                let key = self.signer.get_public_key(&LedgerDevice)?;
+                let address = Address::new(&key);
+                if header.p2 & MODE_INTERACTIVE != 0 {
+                    let mut get_public_key = GetPublicKey::new(&address);
                     let action = get_public_key.exec();
                     self.show();
-                    if let MenuAction::Accept = action {
-                        comm.append(&key);
-                    } else {
+                    if !matches!(action, MenuAction::Accept) {
                         return Err(ErrorCode::UserCancelled);
                     }
                 }
                 comm.append(&key);
+                if header.p2 & MODE_ADDRESS != 0 {
+                    comm.append(address.as_str().as_bytes());
+                }
             }
             INS_SIGN => {
                 let scheme: Scheme = header.p1.try_into()?;
@@ -162,7 +161,17 @@ impl App {
                     COMMAND_START => {
                         self.signer.clear();
                         self.signer.set_scheme(scheme);
-                        self.signer.set_path(get_path(comm)?);
+                        // Path is checked before the message is reviewed
+                        let path = get_path(comm)?;
+                        path::check(&path)?;
+                        self.signer.set_path(path);
+                    }
+                    COMMAND_HINT => {
+                        self.signer.check_scheme(scheme)?;
+
+                        let data_len = comm.apdu_buffer[4] as usize;
+                        let data = &comm.apdu_buffer[5..5 + data_len];
+                        self.signer.set_hint(data)?;
                     }
                     COMMAND_APPEND => {
                         self.signer.check_scheme(scheme)?;
@@ -174,9 +183,25 @@ impl App {
                     COMMAND_FINALIZE => {
                         self.signer.check_scheme(scheme)?;
 
-                        let signature = self.signer.sign()?;
+                        let blind_signing = self.settings.blind_signing();
+                        let warning = match self.signer.prepare_review(blind_signing) {
+                            Ok(warning) => warning,
+                            Err(err) => {
+                                self.signer.clear();
+                                return Err(err);
+                            }
+                        };
+                        let mut sign = Sign::new(self.signer.fields(), warning);
+                        let action = sign.exec();
+                        self.show();
+                        if !matches!(action, MenuAction::Accept) {
+                            self.signer.clear();
+                            return Err(ErrorCode::UserCancelled);
+                        }
+
+                        let signature = self.signer.sign(&LedgerDevice);
                         self.signer.clear();
-                        comm.append(&signature);
+                        comm.append(&signature?);
                     }
                     _ => return Err(ErrorCode::BadP1P2),
                 }
@@ -188,15 +213,7 @@ impl App {
     }
 }
 
-fn get_path(comm: &Comm) -> Result<[u32; 5], ErrorCode> {
-    let data_len = comm.apdu_buffer[4];
-    if data_len != 20 {
-        return Err(ErrorCode::BadLen);
-    }
-    let mut path = [0; 5];
-    for (i, p) in path.iter_mut().enumerate() {
-        let idx = 5 + i * 4;
-        *p = u32::from_le_bytes(comm.apdu_buffer[idx..idx + 4].try_into()?);
-    }
-    Ok(path)
+fn get_path(comm: &Comm) -> Result<Path, ErrorCode> {
+    let data_len = comm.apdu_buffer[4] as usize;
+    path::parse(&comm.apdu_buffer[5..5 + data_len])
 }
@@ -0,0 +1,76 @@
+use core::mem;
+use ledger_device_sdk::{
+    ecc::{CurvesId, Ed25519, SeedDerive},
+    random::LedgerRng,
+};
+use rand_core::{CryptoRng, RngCore};
+use vara_core::{device::Device, error::ErrorCode};
+
+/// Cryptographic functions of the Ledger device.
+pub struct LedgerDevice;
+
+/// Ledger random number generator.
+#[derive(Clone, Copy)]
+pub struct DeviceRng;
+
+#[repr(C)]
+struct PrivateKey {
+    curve: CurvesId,
+    keylength: usize,
+    pub key: [u8; 32],
+}
+
+impl Device for LedgerDevice {
+    type Rng = DeviceRng;
+
+    fn derive_private_key(&self, path: &[u32]) -> Result<[u8; 32], ErrorCode> {
+        let k: PrivateKey = unsafe { mem::transmute(Ed25519::derive_from_path(path)) };
+        Ok(k.key)
+    }
+
+    fn ed25519_public_key(&self, private_key: &[u8; 32]) -> Result<[u8; 32], ErrorCode> {
+        let pk = Ed25519::from(private_key)
+            .public_key()
+            .map_err(|_| ErrorCode::CryptoError)?
+            .pubkey;
+        let mut key = [0; 32];
+        for i in 0..key.len() {
+            key[i] = pk[64 - i];
+        }
+        if (pk[key.len()] & 1) != 0 {
+            key[key.len() - 1] |= 0x80;
+        }
+        Ok(key)
+    }
+
+    fn ed25519_sign(&self, private_key: &[u8; 32], message: &[u8]) -> Result<[u8; 64], ErrorCode> {
+        let (signature, _) = Ed25519::from(private_key)
+            .sign(message)
+            .map_err(|_| ErrorCode::CryptoError)?;
+        Ok(signature)
+    }
+
+    fn rng(&self) -> Self::Rng {
+        DeviceRng
+    }
+}
+
+impl RngCore for DeviceRng {
+    fn next_u32(&mut self) -> u32 {
+        LedgerRng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        LedgerRng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        LedgerRng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        LedgerRng.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for DeviceRng {}
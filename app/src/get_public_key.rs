@@ -2,40 +2,42 @@ use crate::menu::{Menu, MenuAction, MenuPage};
 use core::str;
 use ledger_device_sdk::buttons::ButtonsState;
 use ledger_device_sdk_ui::ui as gadgets;
+use vara_core::{
+    fields::{LINES_PER_PAGE, LINE_LEN},
+    ss58::Address,
+};
+
+/// Number of address characters in a single page.
+const PAGE_LEN: usize = LINE_LEN * LINES_PER_PAGE;
 
 /// Get public key in interactive mode.
-pub struct GetPublicKey {
+pub struct GetPublicKey<'a> {
     /// Menu.
     menu: GetPublicKeyMenu,
-    /// Public key in 0x... hex format.
-    pub key_hex: [u8; 66],
-}
-
-impl Default for GetPublicKey {
-    fn default() -> Self {
-        Self {
-            menu: Default::default(),
-            key_hex: [0; 66],
-        }
-    }
+    /// SS58 address of the public key.
+    address: &'a Address,
 }
 
 #[derive(Default)]
 pub enum GetPublicKeyMenu {
     #[default]
     Review,
-    PublicKey,
+    /// Page index of the address.
+    Address(usize),
     Accept,
     Decline,
 }
 
-impl Menu for GetPublicKey {
+impl Menu for GetPublicKey<'_> {
     fn prev(&mut self) {
         self.page().hide();
         match self.menu {
             GetPublicKeyMenu::Review => self.menu = GetPublicKeyMenu::Decline,
-            GetPublicKeyMenu::PublicKey => self.menu = GetPublicKeyMenu::Review,
-            GetPublicKeyMenu::Accept => self.menu = GetPublicKeyMenu::PublicKey,
+            GetPublicKeyMenu::Address(0) => self.menu = GetPublicKeyMenu::Review,
+            GetPublicKeyMenu::Address(page) => self.menu = GetPublicKeyMenu::Address(page - 1),
+            GetPublicKeyMenu::Accept => {
+                self.menu = GetPublicKeyMenu::Address(self.address_pages() - 1)
+            }
             GetPublicKeyMenu::Decline => self.menu = GetPublicKeyMenu::Accept,
         }
         self.page().show();
@@ -44,8 +46,11 @@ impl Menu for GetPublicKey {
     fn next(&mut self) {
         self.page().hide();
         match self.menu {
-            GetPublicKeyMenu::Review => self.menu = GetPublicKeyMenu::PublicKey,
-            GetPublicKeyMenu::PublicKey => self.menu = GetPublicKeyMenu::Accept,
+            GetPublicKeyMenu::Review => self.menu = GetPublicKeyMenu::Address(0),
+            GetPublicKeyMenu::Address(page) if page + 1 < self.address_pages() => {
+                self.menu = GetPublicKeyMenu::Address(page + 1)
+            }
+            GetPublicKeyMenu::Address(_) => self.menu = GetPublicKeyMenu::Accept,
             GetPublicKeyMenu::Accept => self.menu = GetPublicKeyMenu::Decline,
             GetPublicKeyMenu::Decline => self.menu = GetPublicKeyMenu::Review,
         }
@@ -69,26 +74,33 @@ impl Menu for GetPublicKey {
                 .review_icon()
                 .bold_text("Review")
                 .text("Public key"),
-            GetPublicKeyMenu::PublicKey => MenuPage::new()
-                .text(str::from_utf8(&self.key_hex[..18]).unwrap())
-                .text(str::from_utf8(&self.key_hex[19..34]).unwrap())
-                .text(str::from_utf8(&self.key_hex[35..50]).unwrap())
-                .text(str::from_utf8(&self.key_hex[51..]).unwrap()),
+            GetPublicKeyMenu::Address(page) => {
+                let address = self.address.as_str();
+                let start = (page * PAGE_LEN).min(address.len());
+                let end = (start + PAGE_LEN).min(address.len());
+                let mut menu_page = MenuPage::new().bold_text("Address");
+                for line in address[start..end].as_bytes().chunks(LINE_LEN) {
+                    // SS58 address is ASCII
+                    menu_page = menu_page.text(str::from_utf8(line).unwrap_or_default());
+                }
+                menu_page
+            }
             GetPublicKeyMenu::Accept => MenuPage::new().accept_icon().bold_text("Accept"),
             GetPublicKeyMenu::Decline => MenuPage::new().decline_icon().bold_text("Decline"),
         }
     }
 }
 
-impl GetPublicKey {
-    pub fn new(key: &[u8]) -> Self {
-        let mut key_hex = [0; 66];
-        key_hex[0] = b'0';
-        key_hex[1] = b'x';
-        _ = hex::encode_to_slice(key, &mut key_hex[2..]);
+impl<'a> GetPublicKey<'a> {
+    /// Number of pages needed to show the address.
+    fn address_pages(&self) -> usize {
+        self.address.as_str().len().div_ceil(PAGE_LEN)
+    }
+
+    pub fn new(address: &'a Address) -> Self {
         Self {
             menu: Default::default(),
-            key_hex,
+            address,
         }
     }
 
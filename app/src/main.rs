@@ -2,18 +2,17 @@
 #![no_main]
 
 mod app;
-mod error;
+mod device;
 mod get_public_key;
 mod menu;
 mod settings;
-mod signer;
-mod transcript;
+mod sign;
 
 #[cfg(host_os = "macos")]
 mod macos_lib;
 
 use crate::{app::App, menu::Menu};
-use ledger_device_sdk::io::{Comm, Event};
+use ledger_device_sdk::io::{Comm, Event, Reply};
 
 ledger_device_sdk::set_panic!(ledger_device_sdk::exiting_panic);
 
@@ -31,7 +30,7 @@ extern "C" fn sample_main() {
                 _ = app
                     .handle_command(&mut comm, header)
                     .map(|_| comm.reply_ok())
-                    .map_err(|err| comm.reply(err));
+                    .map_err(|err| comm.reply(Reply(err as u16)));
             }
             _ => (),
         }
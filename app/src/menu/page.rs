@@ -2,7 +2,7 @@ use include_gif::include_gif;
 use ledger_device_sdk_ui::{
     bagls::{
         Icon, Label, CERTIFICATE_ICON, CHECKMARK_ICON, COGGLE_ICON, CROSS_ICON, DASHBOARD_X_ICON,
-        LEFT_ARROW, RIGHT_ARROW,
+        LEFT_ARROW, RIGHT_ARROW, WARNING_ICON,
     },
     bitmaps::{Glyph, BACK},
     layout::{Draw, Location},
@@ -13,7 +13,7 @@ const SPACING: usize = 2;
 const TEXT_HEIGHT: usize = 12;
 const MIDDLE_TEXT_TOP_Y: usize = (SCREEN_HEIGHT - TEXT_HEIGHT) / 2;
 
-const APP_GLYPH: Glyph = Glyph::from_include(include_gif!("assets/icon_x.gif"));
+const APP_GLYPH: Glyph = Glyph::from_include(include_gif!("../assets/icon_x.gif"));
 
 pub enum MenuPage<'a> {
     Empty,
@@ -39,23 +39,27 @@ impl<'a> MenuPage<'a> {
         MenuPage::Empty
     }
 
+    /// Add the item below the others and center them vertically. The item
+    /// is skipped if it doesn't fit, e.g. the text under the icon and the
+    /// title on Nano S.
     pub const fn add(self, item: PageItem<'a>) -> Self {
         match self {
             MenuPage::Empty => MenuPage::Single(item),
             MenuPage::Single(first) => {
-                let mut y = (SCREEN_HEIGHT - first.height() - item.height() - SPACING) / 2;
+                let Some(mut y) = top(first.height() + item.height() + SPACING) else {
+                    return MenuPage::Single(first);
+                };
                 let first = first.set_y(y);
                 y += first.height() + SPACING;
                 let item = item.set_y(y);
                 MenuPage::Double(first, item)
             }
             MenuPage::Double(first, second) => {
-                let mut y = (SCREEN_HEIGHT
-                    - first.height()
-                    - second.height()
-                    - item.height()
-                    - 2 * SPACING)
-                    / 2;
+                let Some(mut y) =
+                    top(first.height() + second.height() + item.height() + 2 * SPACING)
+                else {
+                    return MenuPage::Double(first, second);
+                };
                 let first = first.set_y(y);
                 y += first.height() + SPACING;
                 let second = second.set_y(y);
@@ -64,13 +68,14 @@ impl<'a> MenuPage<'a> {
                 MenuPage::Triple(first, second, item)
             }
             MenuPage::Triple(first, second, third) => {
-                let mut y = (SCREEN_HEIGHT
-                    - first.height()
-                    - second.height()
-                    - third.height()
-                    - item.height()
-                    - 3 * SPACING)
-                    / 2;
+                let Some(mut y) = top(first.height()
+                    + second.height()
+                    + third.height()
+                    + item.height()
+                    + 3 * SPACING)
+                else {
+                    return MenuPage::Triple(first, second, third);
+                };
                 let first = first.set_y(y);
                 y += first.height() + SPACING;
                 let second = second.set_y(y);
@@ -130,6 +135,10 @@ impl<'a> MenuPage<'a> {
         self.icon(CERTIFICATE_ICON)
     }
 
+    pub const fn warning_icon(self) -> Self {
+        self.icon(WARNING_ICON)
+    }
+
     pub const fn accept_icon(self) -> Self {
         self.icon(CHECKMARK_ICON)
     }
@@ -185,6 +194,15 @@ impl<'a> MenuPage<'a> {
     }
 }
 
+/// Top of the items of the given height centered vertically, or `None`
+/// if they don't fit on the screen.
+const fn top(height: usize) -> Option<usize> {
+    match SCREEN_HEIGHT.checked_sub(height) {
+        Some(free) => Some(free / 2),
+        None => None,
+    }
+}
+
 impl<'a> PageItem<'a> {
     pub const fn height(&self) -> usize {
         match self {
@@ -0,0 +1,129 @@
+use crate::menu::{Menu, MenuAction, MenuPage};
+use core::ptr;
+use ledger_device_sdk::{
+    nvm::{AtomicStorage, SingleStorage},
+    NVMData,
+};
+
+/// Size of the settings stored in NVM.
+///
+/// Layout:
+///
+/// | Offset | Size | Value                           |
+/// | ------ | ---- | ------------------------------- |
+/// | 0      | 1    | Layout version                  |
+/// | 1      | 1    | Blind signing (0 / 1)           |
+/// | 2      | 30   | Reserved for new settings (0)   |
+///
+/// New settings must take reserved bytes with zero as the default value,
+/// so the data stored by the previous versions stays valid.
+const SETTINGS_SIZE: usize = 32;
+/// Current layout version. Zero means that the settings were never stored.
+const SETTINGS_VERSION: u8 = 1;
+
+const VERSION_OFFSET: usize = 0;
+const BLIND_SIGNING_OFFSET: usize = 1;
+
+#[link_section = ".nvm_data"]
+static mut DATA: NVMData<AtomicStorage<[u8; SETTINGS_SIZE]>> =
+    NVMData::new(AtomicStorage::new(&[0; SETTINGS_SIZE]));
+
+/// Settings.
+#[derive(Default)]
+pub struct Settings {
+    /// Settings menu.
+    menu: SettingsMenu,
+}
+
+/// Settings menu.
+#[derive(Default)]
+pub enum SettingsMenu {
+    /// Blind signing.
+    #[default]
+    BlindSigning,
+    /// Back to main menu.
+    Back,
+}
+
+impl Menu for Settings {
+    fn prev(&mut self) {
+        self.page().hide();
+        match self.menu {
+            SettingsMenu::BlindSigning => self.menu = SettingsMenu::Back,
+            SettingsMenu::Back => self.menu = SettingsMenu::BlindSigning,
+        }
+        self.page().show();
+    }
+
+    fn next(&mut self) {
+        self.page().hide();
+        match self.menu {
+            SettingsMenu::BlindSigning => self.menu = SettingsMenu::Back,
+            SettingsMenu::Back => self.menu = SettingsMenu::BlindSigning,
+        }
+        self.page().show();
+    }
+
+    fn action(&mut self) -> MenuAction {
+        match self.menu {
+            SettingsMenu::BlindSigning => {
+                self.set_blind_signing(!self.blind_signing());
+                MenuAction::Update
+            }
+            SettingsMenu::Back => {
+                self.menu = Default::default();
+                MenuAction::Exit
+            }
+        }
+    }
+
+    fn page(&self) -> MenuPage {
+        match self.menu {
+            SettingsMenu::BlindSigning => {
+                MenuPage::new()
+                    .bold_text("Blind signing")
+                    .text(if self.blind_signing() {
+                        "Enabled"
+                    } else {
+                        "Disabled"
+                    })
+            }
+            SettingsMenu::Back => MenuPage::new().back_icon().bold_text("Back"),
+        }
+    }
+}
+
+impl Settings {
+    /// Whether payloads that can't be decoded may be signed.
+    pub fn blind_signing(&self) -> bool {
+        self.get(BLIND_SIGNING_OFFSET) != 0
+    }
+
+    fn set_blind_signing(&mut self, enabled: bool) {
+        self.set(BLIND_SIGNING_OFFSET, enabled as u8);
+    }
+
+    /// Get the stored setting or its default value.
+    fn get(&self, offset: usize) -> u8 {
+        let data = load();
+        if data[VERSION_OFFSET] == SETTINGS_VERSION {
+            data[offset]
+        } else {
+            0
+        }
+    }
+
+    fn set(&mut self, offset: usize, value: u8) {
+        let mut data = load();
+        if data[VERSION_OFFSET] != SETTINGS_VERSION {
+            data = [0; SETTINGS_SIZE];
+            data[VERSION_OFFSET] = SETTINGS_VERSION;
+        }
+        data[offset] = value;
+        unsafe { (*ptr::addr_of_mut!(DATA)).get_mut().update(&data) };
+    }
+}
+
+fn load() -> [u8; SETTINGS_SIZE] {
+    *unsafe { (*ptr::addr_of_mut!(DATA)).get_mut().get_ref() }
+}
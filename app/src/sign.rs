@@ -0,0 +1,195 @@
+use crate::menu::{Menu, MenuAction, MenuPage};
+use core::str;
+use ledger_device_sdk::buttons::ButtonsState;
+use ledger_device_sdk_ui::ui as gadgets;
+use vara_core::{fields::Fields, signer::Warning};
+
+/// Maximum length of the field page title.
+const TITLE_LEN: usize = 32;
+
+/// Sign the message in interactive mode.
+pub struct Sign<'a> {
+    /// Menu.
+    menu: SignMenu,
+    /// Fields to be reviewed.
+    fields: &'a Fields,
+    /// Warning shown before the fields.
+    warning: Option<Warning>,
+    /// Title of the current field page, e.g. `Message (1/3)`.
+    title: [u8; TITLE_LEN],
+    title_len: usize,
+}
+
+#[derive(Default, Clone, Copy)]
+pub enum SignMenu {
+    #[default]
+    Review,
+    /// Blind signing or unknown network warning.
+    Warning,
+    /// Field index and page index.
+    Field(usize, usize),
+    Accept,
+    Decline,
+}
+
+impl Menu for Sign<'_> {
+    fn prev(&mut self) {
+        self.page().hide();
+        let menu = match self.menu {
+            // Review is linear, so Accept can't be reached without the fields
+            SignMenu::Review => SignMenu::Review,
+            SignMenu::Warning => SignMenu::Review,
+            SignMenu::Field(index, page) if page > 0 => SignMenu::Field(index, page - 1),
+            SignMenu::Field(index, _) if index > 0 => {
+                SignMenu::Field(index - 1, self.fields.pages(index - 1) - 1)
+            }
+            SignMenu::Field(..) => self.first_page(),
+            SignMenu::Accept => self.last_field(),
+            SignMenu::Decline => SignMenu::Accept,
+        };
+        self.set_menu(menu);
+        self.page().show();
+    }
+
+    fn next(&mut self) {
+        self.page().hide();
+        let menu = match self.menu {
+            SignMenu::Review if self.warning.is_some() => SignMenu::Warning,
+            SignMenu::Review | SignMenu::Warning if !self.fields.is_empty() => {
+                SignMenu::Field(0, 0)
+            }
+            SignMenu::Review | SignMenu::Warning => SignMenu::Accept,
+            SignMenu::Field(index, page) if page + 1 < self.fields.pages(index) => {
+                SignMenu::Field(index, page + 1)
+            }
+            SignMenu::Field(index, _) if index + 1 < self.fields.len() => {
+                SignMenu::Field(index + 1, 0)
+            }
+            SignMenu::Field(..) => SignMenu::Accept,
+            SignMenu::Accept | SignMenu::Decline => SignMenu::Decline,
+        };
+        self.set_menu(menu);
+        self.page().show();
+    }
+
+    fn action(&mut self) -> MenuAction {
+        match self.menu {
+            SignMenu::Accept => MenuAction::Accept,
+            SignMenu::Decline => {
+                self.set_menu(Default::default());
+                MenuAction::Decline
+            }
+            _ => MenuAction::Nothing,
+        }
+    }
+
+    fn page(&self) -> MenuPage {
+        match self.menu {
+            SignMenu::Review => MenuPage::new()
+                .review_icon()
+                .bold_text("Review")
+                .text("Message"),
+            SignMenu::Warning => match self.warning {
+                Some(Warning::UnknownNetwork) => MenuPage::new()
+                    .warning_icon()
+                    .bold_text("Unknown network")
+                    .text("Check genesis hash"),
+                _ => MenuPage::new()
+                    .warning_icon()
+                    .bold_text("Blind signing")
+                    .text("Unknown payload"),
+            },
+            SignMenu::Field(index, page) => {
+                let mut menu_page = MenuPage::new()
+                    .bold_text(str::from_utf8(&self.title[..self.title_len]).unwrap());
+                for line in self.fields.page_lines(index, page) {
+                    if !line.is_empty() {
+                        menu_page = menu_page.text(line);
+                    }
+                }
+                menu_page
+            }
+            SignMenu::Accept => MenuPage::new().accept_icon().bold_text("Accept"),
+            SignMenu::Decline => MenuPage::new().decline_icon().bold_text("Decline"),
+        }
+    }
+}
+
+impl<'a> Sign<'a> {
+    pub fn new(fields: &'a Fields, warning: Option<Warning>) -> Self {
+        Self {
+            menu: Default::default(),
+            fields,
+            warning,
+            title: [0; TITLE_LEN],
+            title_len: 0,
+        }
+    }
+
+    pub fn exec(&mut self) -> MenuAction {
+        self.set_menu(Default::default());
+        self.show();
+        let mut state = ButtonsState::new();
+        loop {
+            if let Some(button) = gadgets::get_event(&mut state) {
+                let action = self.handle_button_event(button);
+                if matches!(action, MenuAction::Accept | MenuAction::Decline) {
+                    return action;
+                }
+            }
+        }
+    }
+
+    /// Page before the first field.
+    fn first_page(&self) -> SignMenu {
+        if self.warning.is_some() {
+            SignMenu::Warning
+        } else {
+            SignMenu::Review
+        }
+    }
+
+    fn last_field(&self) -> SignMenu {
+        match self.fields.len() {
+            0 => self.first_page(),
+            len => SignMenu::Field(len - 1, self.fields.pages(len - 1) - 1),
+        }
+    }
+
+    fn set_menu(&mut self, menu: SignMenu) {
+        let fields = self.fields;
+        self.menu = menu;
+        self.title_len = 0;
+        if let SignMenu::Field(index, page) = menu {
+            self.append_title(fields.label(index).as_bytes());
+            let pages = fields.pages(index);
+            if pages > 1 {
+                self.append_title(b" (");
+                self.append_number(page + 1);
+                self.append_title(b"/");
+                self.append_number(pages);
+                self.append_title(b")");
+            }
+        }
+    }
+
+    fn append_title(&mut self, bytes: &[u8]) {
+        let len = bytes.len().min(TITLE_LEN - self.title_len);
+        self.title[self.title_len..self.title_len + len].copy_from_slice(&bytes[..len]);
+        self.title_len += len;
+    }
+
+    fn append_number(&mut self, mut n: usize) {
+        let mut digits = [0; 20];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        self.append_title(&digits[i..]);
+    }
+}
@@ -0,0 +1,34 @@
+use vara_core::error::ErrorCode;
+
+/// Client error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Device replied with the error status.
+    #[error("device error {0:?} (0x{code:04x})", code = *.0 as u16)]
+    Device(ErrorCode),
+    /// Device replied with the status that is not known.
+    #[error("unknown status 0x{0:04x}")]
+    Status(u16),
+    /// Response is malformed.
+    #[error("invalid response")]
+    Response,
+    /// Command data doesn't fit in a single APDU.
+    #[error("command data is longer than 255 bytes")]
+    DataLen,
+    /// Derivation path can't be parsed.
+    #[error("invalid derivation path")]
+    Path,
+    /// Sails hint can't be parsed or is too long.
+    #[error("invalid Sails hint")]
+    Hint,
+    /// No Ledger device is connected or the HID transport is disabled.
+    #[error("device not found")]
+    NotFound,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "hid")]
+    #[error(transparent)]
+    Hid(#[from] hidapi::HidError),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
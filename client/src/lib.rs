@@ -0,0 +1,291 @@
+//! Host client of the Vara Ledger application.
+//!
+//! ```no_run
+//! use vara_ledger::{transport::Speculos, Scheme, Vara};
+//!
+//! let mut vara = Vara::new(Speculos::connect("127.0.0.1:9999")?);
+//! let path = vara_ledger::parse_path("44'/913'/0'/0'/0'")?;
+//! let public_key = vara.get_public_key(Scheme::Sr25519, &path, false)?;
+//! println!("{}", public_key.address);
+//! # Ok::<_, vara_ledger::Error>(())
+//! ```
+
+mod error;
+pub mod sails;
+pub mod transport;
+
+pub use error::{Error, Result};
+pub use vara_core::{error::ErrorCode, path::Path, signer::Scheme};
+
+use sails::Hint;
+use transport::Transport;
+use vara_core::{apdu::*, path::PATH_LEN};
+
+/// Version of the application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+/// Public key and its SS58 address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub key: [u8; 32],
+    pub address: String,
+}
+
+/// Client of the Vara application.
+pub struct Vara<T> {
+    transport: T,
+}
+
+impl<T: Transport> Vara<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn get_version(&mut self) -> Result<Version> {
+        let data = self.send(INS_GET_VERSION, 0, 0, &[])?;
+        let data: [u8; 6] = data.try_into().map_err(|_| Error::Response)?;
+        Ok(Version {
+            major: u16::from_be_bytes([data[0], data[1]]),
+            minor: u16::from_be_bytes([data[2], data[3]]),
+            patch: u16::from_be_bytes([data[4], data[5]]),
+        })
+    }
+
+    /// Get the public key and its address.
+    ///
+    /// In interactive mode the device shows the address and returns it
+    /// only if the user accepts it.
+    pub fn get_public_key(
+        &mut self,
+        scheme: Scheme,
+        path: &Path,
+        interactive: bool,
+    ) -> Result<PublicKey> {
+        let mut mode = MODE_ADDRESS;
+        if interactive {
+            mode |= MODE_INTERACTIVE;
+        }
+        let data = self.send(INS_GET_PUBLIC_KEY, scheme as u8, mode, &path_bytes(path))?;
+        if data.len() < 32 {
+            return Err(Error::Response);
+        }
+        let (key, address) = data.split_at(32);
+        Ok(PublicKey {
+            key: key.try_into().map_err(|_| Error::Response)?,
+            address: String::from_utf8(address.to_vec()).map_err(|_| Error::Response)?,
+        })
+    }
+
+    /// Sign the message after the user reviews it on the device.
+    pub fn sign(&mut self, scheme: Scheme, path: &Path, message: &[u8]) -> Result<[u8; 64]> {
+        self.sign_with_hint(scheme, path, None, message)
+    }
+
+    /// Sign the message after the user reviews it on the device. The
+    /// payload of the message to a Sails program is shown decoded with the
+    /// hint.
+    pub fn sign_with_hint(
+        &mut self,
+        scheme: Scheme,
+        path: &Path,
+        hint: Option<&Hint>,
+        message: &[u8],
+    ) -> Result<[u8; 64]> {
+        let p1 = scheme as u8;
+        self.send(INS_SIGN, p1, COMMAND_START, &path_bytes(path))?;
+        if let Some(hint) = hint {
+            self.send(INS_SIGN, p1, COMMAND_HINT, &hint.encode()?)?;
+        }
+        for chunk in message.chunks(MAX_DATA_LEN) {
+            self.send(INS_SIGN, p1, COMMAND_APPEND, chunk)?;
+        }
+        let data = self.send(INS_SIGN, p1, COMMAND_FINALIZE, &[])?;
+        data.try_into().map_err(|_| Error::Response)
+    }
+
+    /// Send the command and return the response data.
+    fn send(&mut self, ins: u8, p1: u8, p2: u8, data: &[u8]) -> Result<Vec<u8>> {
+        let len = u8::try_from(data.len()).map_err(|_| Error::DataLen)?;
+        let mut apdu = vec![APDU_CLA, ins, p1, p2, len];
+        apdu.extend_from_slice(data);
+        let (data, status) = self.transport.exchange(&apdu)?;
+        match ErrorCode::try_from(status) {
+            Ok(ErrorCode::Ok) => Ok(data),
+            Ok(code) => Err(Error::Device(code)),
+            Err(status) => Err(Error::Status(status)),
+        }
+    }
+}
+
+/// Parse the derivation path, e.g. `44'/913'/0'/0'/0'`.
+///
+/// Hardened components are marked with `'` or `h`.
+pub fn parse_path(s: &str) -> Result<Path> {
+    let mut path = [0; PATH_LEN];
+    let mut components = s.trim_start_matches("m/").split('/');
+    for p in path.iter_mut() {
+        let component = components.next().ok_or(Error::Path)?;
+        let (index, hardened) = match component.strip_suffix(['\'', 'h']) {
+            Some(index) => (index, true),
+            None => (component, false),
+        };
+        let index: u32 = index.parse().map_err(|_| Error::Path)?;
+        if index & 0x80000000 != 0 {
+            return Err(Error::Path);
+        }
+        *p = if hardened { index | 0x80000000 } else { index };
+    }
+    if components.next().is_some() {
+        return Err(Error::Path);
+    }
+    Ok(path)
+}
+
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.iter().flat_map(|p| p.to_le_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: Path = [0x8000002c, 0x80000391, 0x80000000, 0x80000000, 0x80000001];
+
+    /// Transport that records the APDUs and replies with the given responses.
+    struct Mock {
+        apdus: Vec<Vec<u8>>,
+        responses: Vec<(Vec<u8>, u16)>,
+    }
+
+    impl Mock {
+        fn new(responses: &[(&[u8], u16)]) -> Self {
+            Self {
+                apdus: Vec::new(),
+                responses: responses
+                    .iter()
+                    .rev()
+                    .map(|(data, status)| (data.to_vec(), *status))
+                    .collect(),
+            }
+        }
+    }
+
+    impl Transport for Mock {
+        fn exchange(&mut self, apdu: &[u8]) -> Result<(Vec<u8>, u16)> {
+            self.apdus.push(apdu.to_vec());
+            Ok(self.responses.pop().unwrap_or((Vec::new(), 0x9000)))
+        }
+    }
+
+    #[test]
+    fn get_version() {
+        let mut vara = Vara::new(Mock::new(&[(&[0, 1, 0, 20, 0x0b, 0x18], 0x9000)]));
+        let version = vara.get_version().unwrap();
+        assert_eq!(
+            version,
+            Version {
+                major: 1,
+                minor: 20,
+                patch: 2840
+            }
+        );
+        assert_eq!(vara.transport.apdus, [[0x89, 0x00, 0x00, 0x00, 0x00]]);
+    }
+
+    #[test]
+    fn get_public_key() {
+        let mut response = [0xd4; 32].to_vec();
+        response.extend_from_slice(b"kGkLEU3e3XXkJp2WK4eNpVmSab5xUNL9QtmLPh8QfCL2EgotW");
+        let mut vara = Vara::new(Mock::new(&[(&response, 0x9000)]));
+        let public_key = vara.get_public_key(Scheme::Sr25519, &PATH, true).unwrap();
+        assert_eq!(public_key.key, [0xd4; 32]);
+        assert_eq!(
+            public_key.address,
+            "kGkLEU3e3XXkJp2WK4eNpVmSab5xUNL9QtmLPh8QfCL2EgotW"
+        );
+        assert_eq!(
+            hex::encode(&vara.transport.apdus[0]),
+            "89010103142c00008091030080000000800000008001000080"
+        );
+    }
+
+    #[test]
+    fn sign_in_chunks() {
+        let mut vara = Vara::new(Mock::new(&[
+            (&[], 0x9000),
+            (&[], 0x9000),
+            (&[], 0x9000),
+            (&[7; 64], 0x9000),
+        ]));
+        let message = [1; 300];
+        let signature = vara.sign(Scheme::Ed25519, &PATH, &message).unwrap();
+        assert_eq!(signature, [7; 64]);
+
+        let apdus = &vara.transport.apdus;
+        assert_eq!(apdus.len(), 4);
+        assert_eq!(apdus[0][..5], [0x89, 0x02, 0x00, 0x00, 20]);
+        assert_eq!(apdus[1][..5], [0x89, 0x02, 0x00, 0x01, 255]);
+        assert_eq!(apdus[1].len(), 5 + 255);
+        assert_eq!(apdus[2][..5], [0x89, 0x02, 0x00, 0x01, 45]);
+        assert_eq!(apdus[3], [0x89, 0x02, 0x00, 0x02, 0]);
+    }
+
+    #[test]
+    fn sign_with_hint() {
+        let mut vara = Vara::new(Mock::new(&[
+            (&[], 0x9000),
+            (&[], 0x9000),
+            (&[], 0x9000),
+            (&[7; 64], 0x9000),
+        ]));
+        let hint = "Vft/TotalSupply()".parse().unwrap();
+        vara.sign_with_hint(Scheme::Sr25519, &PATH, Some(&hint), b"Hello")
+            .unwrap();
+
+        let apdus = &vara.transport.apdus;
+        assert_eq!(apdus.len(), 4);
+        assert_eq!(apdus[1], b"\x89\x02\x01\x03\x10\x03Vft\x0bTotalSupply");
+        assert_eq!(apdus[2][..5], [0x89, 0x02, 0x01, 0x01, 5]);
+    }
+
+    #[test]
+    fn status_error() {
+        let mut vara = Vara::new(Mock::new(&[(&[], 0x6e04)]));
+        assert!(matches!(
+            vara.sign(Scheme::Sr25519, &PATH, b"Hello"),
+            Err(Error::Device(ErrorCode::UserCancelled))
+        ));
+        assert_eq!(vara.transport.apdus.len(), 1);
+
+        let mut vara = Vara::new(Mock::new(&[(&[], 0x6a80)]));
+        assert!(matches!(vara.get_version(), Err(Error::Status(0x6a80))));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_path("44'/913'/0'/0h/1'").unwrap(),
+            [0x8000002c, 0x80000391, 0x80000000, 0x80000000, 0x80000001]
+        );
+        assert_eq!(parse_path("m/44/913/0/0/1").unwrap(), [44, 913, 0, 0, 1]);
+        assert!(parse_path("44'/913'/0'/0'").is_err());
+        assert!(parse_path("44'/913'/0'/0'/0'/0'").is_err());
+        assert!(parse_path("44'/913'/x'/0'/0'").is_err());
+        assert!(parse_path("44'/913'/2147483648'/0'/0'").is_err());
+    }
+
+    #[test]
+    fn send_long_data() {
+        let mut vara = Vara::new(Mock::new(&[]));
+        assert!(matches!(
+            vara.send(INS_SIGN, 0, COMMAND_APPEND, &[0; 256]),
+            Err(Error::DataLen)
+        ));
+        assert!(vara.transport.apdus.is_empty());
+    }
+}
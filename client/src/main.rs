@@ -0,0 +1,119 @@
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+use vara_ledger::{
+    sails::Hint,
+    transport::{Speculos, Transport},
+    Error, Path, Result, Scheme, Vara,
+};
+
+/// Vara Ledger application client.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Connect to the Speculos APDU server instead of the USB device.
+    #[arg(long, global = true, value_name = "ADDRESS", num_args = 0..=1,
+          default_missing_value = Speculos::DEFAULT_ADDRESS)]
+    speculos: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Get the application version.
+    Version,
+    /// Get the public key and its SS58 address.
+    PublicKey {
+        #[command(flatten)]
+        key: KeyArgs,
+        /// Show the address on the device and wait for the confirmation.
+        #[arg(short, long)]
+        interactive: bool,
+    },
+    /// Sign the message after reviewing it on the device.
+    Sign {
+        #[command(flatten)]
+        key: KeyArgs,
+        /// Sails method to decode the message payload with, e.g.
+        /// `Vft/Transfer(to: actor_id, value: u128)`.
+        #[arg(long, value_name = "METHOD")]
+        sails: Option<Hint>,
+        /// Message in hex format.
+        #[arg(value_parser = parse_hex)]
+        message: Vec<u8>,
+    },
+}
+
+#[derive(clap::Args)]
+struct KeyArgs {
+    /// Signature scheme: ed25519 or sr25519.
+    #[arg(short, long, default_value = "sr25519", value_parser = parse_scheme)]
+    scheme: Scheme,
+    /// Derivation path.
+    #[arg(short, long, default_value = "44'/913'/0'/0'/0'", value_parser = parse_path)]
+    path: Path,
+}
+
+fn parse_scheme(s: &str) -> Result<Scheme, String> {
+    match s.to_lowercase().as_str() {
+        "ed25519" => Ok(Scheme::Ed25519),
+        "sr25519" => Ok(Scheme::Sr25519),
+        _ => Err("expected ed25519 or sr25519".into()),
+    }
+}
+
+fn parse_path(s: &str) -> Result<Path, String> {
+    vara_ledger::parse_path(s).map_err(|err| err.to_string())
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|err| err.to_string())
+}
+
+fn transport(speculos: Option<String>) -> Result<Box<dyn Transport>> {
+    match speculos {
+        Some(address) => Ok(Box::new(Speculos::connect(address)?)),
+        #[cfg(feature = "hid")]
+        None => Ok(Box::new(vara_ledger::transport::Hid::open()?)),
+        #[cfg(not(feature = "hid"))]
+        None => Err(Error::NotFound),
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let mut vara = Vara::new(transport(cli.speculos)?);
+    match cli.command {
+        Command::Version => {
+            let version = vara.get_version()?;
+            println!("{}.{}.{}", version.major, version.minor, version.patch);
+        }
+        Command::PublicKey { key, interactive } => {
+            let public_key = vara.get_public_key(key.scheme, &key.path, interactive)?;
+            println!("Public key: 0x{}", hex::encode(public_key.key));
+            println!("Address: {}", public_key.address);
+        }
+        Command::Sign {
+            key,
+            sails,
+            message,
+        } => {
+            if let Some(hint) = &sails {
+                // Shown by the device to check that the same hint is used
+                eprintln!("Hint hash: 0x{}", hex::encode(hint.hash()?));
+            }
+            let signature = vara.sign_with_hint(key.scheme, &key.path, sails.as_ref(), &message)?;
+            println!("0x{}", hex::encode(signature));
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
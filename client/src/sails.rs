@@ -0,0 +1,131 @@
+//! Hint for decoding the payload of the message to a Sails program.
+
+use crate::{Error, Result};
+use std::str::FromStr;
+use vara_core::sails::{self, MAX_HINT_LEN};
+
+pub use vara_core::sails::Type;
+
+/// Sails method the message payload is decoded with.
+///
+/// It is parsed from the method signature in the Sails IDL syntax, e.g.
+/// `Vft/Transfer(to: actor_id, value: u128)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hint {
+    pub service: String,
+    pub method: String,
+    pub args: Vec<(String, Type)>,
+}
+
+impl Hint {
+    /// Encode the hint to be sent to the device.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        encode_name(&mut data, &self.service)?;
+        encode_name(&mut data, &self.method)?;
+        for (name, ty) in &self.args {
+            encode_name(&mut data, name)?;
+            data.push(*ty as u8);
+        }
+        if data.len() > MAX_HINT_LEN {
+            return Err(Error::Hint);
+        }
+        Ok(data)
+    }
+
+    /// Blake2b-256 hash of the encoded hint shown by the device as
+    /// `Hint hash`.
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        let hint = sails::Hint::parse(&self.encode()?).map_err(|_| Error::Hint)?;
+        Ok(*hint.hash())
+    }
+}
+
+impl FromStr for Hint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (route, args) = s.trim().split_once('(').ok_or(Error::Hint)?;
+        let (service, method) = route.split_once('/').ok_or(Error::Hint)?;
+        let args = args.strip_suffix(')').ok_or(Error::Hint)?;
+        let args = args
+            .split(',')
+            .map(str::trim)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| {
+                let (name, ty) = arg.split_once(':').ok_or(Error::Hint)?;
+                Ok((name.trim().into(), parse_type(ty.trim())?))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            service: service.trim().into(),
+            method: method.trim().into(),
+            args,
+        })
+    }
+}
+
+fn parse_type(s: &str) -> Result<Type> {
+    let ty = match s {
+        "bool" => Type::Bool,
+        "u8" => Type::U8,
+        "u16" => Type::U16,
+        "u32" => Type::U32,
+        "u64" => Type::U64,
+        "u128" => Type::U128,
+        "str" => Type::Str,
+        "actor_id" => Type::ActorId,
+        "h256" => Type::H256,
+        "vec u8" => Type::Bytes,
+        _ => return Err(Error::Hint),
+    };
+    Ok(ty)
+}
+
+fn encode_name(data: &mut Vec<u8>, name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(Error::Hint);
+    }
+    data.push(name.len().try_into().map_err(|_| Error::Hint)?);
+    data.extend_from_slice(name.as_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_encode() {
+        let hint: Hint = "Vft/Transfer(to: actor_id, value: u128)".parse().unwrap();
+        assert_eq!(
+            hint,
+            Hint {
+                service: "Vft".into(),
+                method: "Transfer".into(),
+                args: vec![("to".into(), Type::ActorId), ("value".into(), Type::U128)],
+            }
+        );
+        assert_eq!(
+            hint.encode().unwrap(),
+            b"\x03Vft\x08Transfer\x02to\x07\x05value\x05"
+        );
+        assert_eq!(
+            hex::encode(hint.hash().unwrap()),
+            "8bc1ebb3f03d8e8198b5c2c37db8063508e8ee0252ffef0c17470bc70faf8981"
+        );
+
+        let hint: Hint = "Vft/TotalSupply()".parse().unwrap();
+        assert!(hint.args.is_empty());
+        assert_eq!(hint.encode().unwrap(), b"\x03Vft\x0bTotalSupply");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("Vft/Transfer".parse::<Hint>().is_err());
+        assert!("Transfer(to: actor_id)".parse::<Hint>().is_err());
+        assert!("Vft/Transfer(to)".parse::<Hint>().is_err());
+        assert!("Vft/Transfer(to: address)".parse::<Hint>().is_err());
+        assert!("/Transfer()".parse::<Hint>().unwrap().encode().is_err());
+    }
+}
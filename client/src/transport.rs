@@ -0,0 +1,23 @@
+//! APDU transports.
+
+#[cfg(feature = "hid")]
+mod hid;
+mod speculos;
+
+#[cfg(feature = "hid")]
+pub use self::hid::Hid;
+pub use self::speculos::Speculos;
+
+use crate::Result;
+
+/// APDU transport.
+pub trait Transport {
+    /// Send the APDU and return the response data and the status word.
+    fn exchange(&mut self, apdu: &[u8]) -> Result<(Vec<u8>, u16)>;
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn exchange(&mut self, apdu: &[u8]) -> Result<(Vec<u8>, u16)> {
+        (**self).exchange(apdu)
+    }
+}
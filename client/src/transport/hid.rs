@@ -0,0 +1,126 @@
+use super::Transport;
+use crate::{Error, Result};
+use hidapi::{HidApi, HidDevice};
+
+/// USB vendor ID of Ledger.
+const LEDGER_VENDOR_ID: u16 = 0x2c97;
+/// HID usage page of the Ledger APDU interface.
+const LEDGER_USAGE_PAGE: u16 = 0xffa0;
+/// Channel of the APDU exchange.
+const CHANNEL: u16 = 0x0101;
+/// Tag of the APDU packet.
+const TAG_APDU: u8 = 0x05;
+/// Size of the HID report.
+const PACKET_LEN: usize = 64;
+/// Size of the packet header: channel, tag and sequence index.
+const HEADER_LEN: usize = 5;
+/// Response timeout in milliseconds. The user may need time to review.
+const TIMEOUT_MS: i32 = 5 * 60 * 1000;
+
+/// Transport to the Ledger device connected through USB HID.
+pub struct Hid {
+    device: HidDevice,
+}
+
+impl Hid {
+    /// Open the first connected Ledger device.
+    pub fn open() -> Result<Self> {
+        let api = HidApi::new()?;
+        let info = api
+            .device_list()
+            .find(|info| {
+                info.vendor_id() == LEDGER_VENDOR_ID
+                    && (info.usage_page() == LEDGER_USAGE_PAGE || info.interface_number() == 0)
+            })
+            .ok_or(Error::NotFound)?;
+        let device = info.open_device(&api)?;
+        Ok(Self { device })
+    }
+}
+
+impl Transport for Hid {
+    fn exchange(&mut self, apdu: &[u8]) -> Result<(Vec<u8>, u16)> {
+        for packet in packets(apdu) {
+            // Report ID goes first
+            let mut report = [0; PACKET_LEN + 1];
+            report[1..].copy_from_slice(&packet);
+            self.device.write(&report)?;
+        }
+
+        let mut response = Vec::new();
+        let mut response_len = None;
+        let mut sequence = 0;
+        while response_len.is_none_or(|len| response.len() < len) {
+            let mut packet = [0; PACKET_LEN];
+            if self.device.read_timeout(&mut packet, TIMEOUT_MS)? != PACKET_LEN {
+                return Err(Error::Response);
+            }
+            let mut data = parse_header(&packet, sequence)?;
+            if sequence == 0 {
+                response_len = Some(u16::from_be_bytes([data[0], data[1]]) as usize);
+                data = &data[2..];
+            }
+            response.extend_from_slice(data);
+            sequence += 1;
+        }
+
+        let len = response_len.unwrap_or_default();
+        if len < 2 {
+            return Err(Error::Response);
+        }
+        response.truncate(len);
+        let status = u16::from_be_bytes([response[len - 2], response[len - 1]]);
+        response.truncate(len - 2);
+        Ok((response, status))
+    }
+}
+
+/// Split the APDU into HID packets. The first packet contains the APDU
+/// length.
+fn packets(apdu: &[u8]) -> Vec<[u8; PACKET_LEN]> {
+    let mut data = Vec::with_capacity(2 + apdu.len());
+    data.extend_from_slice(&(apdu.len() as u16).to_be_bytes());
+    data.extend_from_slice(apdu);
+    data.chunks(PACKET_LEN - HEADER_LEN)
+        .enumerate()
+        .map(|(sequence, chunk)| {
+            let mut packet = [0; PACKET_LEN];
+            packet[..2].copy_from_slice(&CHANNEL.to_be_bytes());
+            packet[2] = TAG_APDU;
+            packet[3..HEADER_LEN].copy_from_slice(&(sequence as u16).to_be_bytes());
+            packet[HEADER_LEN..HEADER_LEN + chunk.len()].copy_from_slice(chunk);
+            packet
+        })
+        .collect()
+}
+
+/// Check the packet header and return the packet data.
+fn parse_header(packet: &[u8; PACKET_LEN], sequence: u16) -> Result<&[u8]> {
+    if packet[..2] != CHANNEL.to_be_bytes()
+        || packet[2] != TAG_APDU
+        || packet[3..HEADER_LEN] != sequence.to_be_bytes()
+    {
+        return Err(Error::Response);
+    }
+    Ok(&packet[HEADER_LEN..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_apdu() {
+        let apdu = [0xaa; 100];
+        let packets = packets(&apdu);
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0][..7], [0x01, 0x01, 0x05, 0x00, 0x00, 0x00, 100]);
+        assert_eq!(packets[0][7..], [0xaa; 57]);
+        assert_eq!(packets[1][..5], [0x01, 0x01, 0x05, 0x00, 0x01]);
+        assert_eq!(packets[1][5..48], [0xaa; 43]);
+        assert_eq!(packets[1][48..], [0; 16]);
+
+        assert_eq!(parse_header(&packets[1], 1).unwrap()[0], 0xaa);
+        assert!(parse_header(&packets[1], 0).is_err());
+    }
+}
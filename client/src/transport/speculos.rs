@@ -0,0 +1,69 @@
+use super::Transport;
+use crate::Result;
+use std::{
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+};
+
+/// Transport to the Speculos emulator through its APDU TCP server.
+///
+/// The APDU is sent prefixed with its big-endian 4-byte length. The
+/// response data is received prefixed with its length as well and is
+/// followed by the status word.
+pub struct Speculos {
+    stream: TcpStream,
+}
+
+impl Speculos {
+    /// Default address of the Speculos APDU server.
+    pub const DEFAULT_ADDRESS: &'static str = "127.0.0.1:9999";
+
+    pub fn connect(address: impl ToSocketAddrs) -> Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        Ok(Self { stream })
+    }
+}
+
+impl Transport for Speculos {
+    fn exchange(&mut self, apdu: &[u8]) -> Result<(Vec<u8>, u16)> {
+        let mut request = Vec::with_capacity(4 + apdu.len());
+        request.extend_from_slice(&(apdu.len() as u32).to_be_bytes());
+        request.extend_from_slice(apdu);
+        self.stream.write_all(&request)?;
+
+        let mut len = [0; 4];
+        self.stream.read_exact(&mut len)?;
+        let mut data = vec![0; u32::from_be_bytes(len) as usize];
+        self.stream.read_exact(&mut data)?;
+        let mut status = [0; 2];
+        self.stream.read_exact(&mut status)?;
+        Ok((data, u16::from_be_bytes(status)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{net::TcpListener, thread};
+
+    #[test]
+    fn exchange() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 6];
+            stream.read_exact(&mut request).unwrap();
+            assert_eq!(request, [0, 0, 0, 2, 0x89, 0x00]);
+            stream
+                .write_all(&[0, 0, 0, 6, 0, 0, 0, 0, 0, 2, 0x90, 0x00])
+                .unwrap();
+        });
+
+        let mut speculos = Speculos::connect(address).unwrap();
+        let (data, status) = speculos.exchange(&[0x89, 0x00]).unwrap();
+        assert_eq!(data, [0, 0, 0, 0, 0, 2]);
+        assert_eq!(status, 0x9000);
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,344 @@
+//! Speculos harness for the integration tests.
+//!
+//! The tests start Speculos with the app built for the device model and the
+//! test mnemonic from the README. Environment variables:
+//!
+//! - `SPECULOS`: Speculos command (`speculos` by default);
+//! - `VARA_MODEL`: device model of [`Emulator::start`]: `nanos`, `nanosplus`
+//!   or `nanox` (`nanosplus` by default);
+//! - `VARA_TARGET_DIR`: target directory of the app (`app/target` by default).
+
+#![allow(dead_code)]
+
+use hmac::{Hmac, Mac};
+use schnorrkel::{ExpansionMode, MiniSecretKey};
+use sha2::{Sha256, Sha512};
+use std::{
+    env,
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+    sync::{Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+use vara_ledger::{transport::Speculos, Path, Scheme, Vara};
+
+/// Test mnemonic from the README.
+pub const MNEMONIC: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+pub const PATH: Path = [0x8000002c, 0x80000391, 0x80000000, 0x80000000, 0x80000000];
+
+const APDU_PORT: u16 = 9999;
+const API_PORT: u16 = 5000;
+
+/// Time to wait for Speculos to start.
+const START_TIMEOUT: Duration = Duration::from_secs(30);
+/// Time to wait for the screen to be updated before pressing the button.
+const BUTTON_DELAY: Duration = Duration::from_millis(500);
+/// Maximum number of pages walked through to find the given one.
+const MAX_PAGES: usize = 100;
+
+/// Speculos is started once at a time as the ports are fixed.
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Clone, Copy)]
+pub enum Model {
+    NanoS,
+    NanoSPlus,
+    NanoX,
+}
+
+#[derive(Clone, Copy)]
+pub enum Button {
+    Left,
+    Right,
+    Both,
+}
+
+/// Running Speculos instance killed on drop.
+pub struct Emulator {
+    process: Child,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Model {
+    /// Model name in Speculos.
+    pub fn name(self) -> &'static str {
+        match self {
+            Model::NanoS => "nanos",
+            Model::NanoSPlus => "nanosp",
+            Model::NanoX => "nanox",
+        }
+    }
+
+    /// Build target of the app.
+    fn target(self) -> &'static str {
+        match self {
+            Model::NanoS => "nanos",
+            Model::NanoSPlus => "nanosplus",
+            Model::NanoX => "nanox",
+        }
+    }
+}
+
+impl Emulator {
+    pub fn start() -> Self {
+        let model = match env::var("VARA_MODEL").as_deref() {
+            Ok("nanos") => Model::NanoS,
+            Ok("nanox") => Model::NanoX,
+            Ok("nanosplus") | Err(_) => Model::NanoSPlus,
+            Ok(model) => panic!("unknown model {model}"),
+        };
+        Self::start_model(model)
+    }
+
+    pub fn start_model(model: Model) -> Self {
+        let lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let speculos = env::var("SPECULOS").unwrap_or_else(|_| "speculos".into());
+        let target_dir = env::var("VARA_TARGET_DIR")
+            .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/../app/target").into());
+        let app = format!("{target_dir}/{}/release/app-vara", model.target());
+        let process = Command::new(speculos)
+            .args(["--model", model.name(), "--display", "headless"])
+            .args(["--apdu-port", &APDU_PORT.to_string()])
+            .args(["--api-port", &API_PORT.to_string()])
+            .args(["--seed", MNEMONIC])
+            .arg(app)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to start Speculos");
+        let emulator = Self {
+            process,
+            _lock: lock,
+        };
+
+        let start = Instant::now();
+        while TcpStream::connect(("127.0.0.1", API_PORT)).is_err()
+            || TcpStream::connect(("127.0.0.1", APDU_PORT)).is_err()
+        {
+            assert!(start.elapsed() < START_TIMEOUT, "Speculos is not started");
+            thread::sleep(Duration::from_millis(100));
+        }
+        emulator
+    }
+
+    pub fn transport(&self) -> Speculos {
+        Speculos::connect(("127.0.0.1", APDU_PORT)).unwrap()
+    }
+
+    pub fn client(&self) -> Vara<Speculos> {
+        Vara::new(self.transport())
+    }
+
+    /// Press and release the button through the automation API.
+    pub fn press(&self, button: Button) {
+        thread::sleep(BUTTON_DELAY);
+        let name = match button {
+            Button::Left => "left",
+            Button::Right => "right",
+            Button::Both => "both",
+        };
+        request(
+            "POST",
+            &format!("/button/{name}"),
+            r#"{"action":"press-and-release"}"#,
+        );
+    }
+
+    pub fn press_all(&self, buttons: &[Button]) {
+        for button in buttons {
+            self.press(*button);
+        }
+    }
+
+    /// Take the screenshot of the current screen in PNG format.
+    pub fn screenshot(&self) -> Vec<u8> {
+        thread::sleep(BUTTON_DELAY);
+        request("GET", "/screenshot", "")
+    }
+
+    /// Text shown on the current screen.
+    pub fn screen_text(&self) -> String {
+        thread::sleep(BUTTON_DELAY);
+        String::from_utf8(request("GET", "/events?currentscreenonly=true", "")).unwrap()
+    }
+
+    /// Go through the review pages until the one with the given text.
+    pub fn press_until(&self, text: &str) {
+        let text = format!("\"{text}\"");
+        for _ in 0..MAX_PAGES {
+            if self.screen_text().contains(&text) {
+                return;
+            }
+            self.press(Button::Right);
+        }
+        panic!("no {text} page");
+    }
+
+    /// Accept the review after going through all its pages.
+    pub fn accept(&self) {
+        self.press_until("Accept");
+        self.press(Button::Both);
+    }
+
+    /// Decline the review after going through all its pages.
+    pub fn decline(&self) {
+        self.press_until("Decline");
+        self.press(Button::Both);
+    }
+
+    /// Toggle blind signing in the settings and return to the main menu.
+    pub fn toggle_blind_signing(&self) {
+        self.press_all(&[
+            // Version, Settings
+            Button::Right,
+            Button::Right,
+            // Blind signing, toggle
+            Button::Both,
+            Button::Both,
+            // Back
+            Button::Right,
+            Button::Both,
+            // Quit, App ready
+            Button::Right,
+            Button::Right,
+        ]);
+    }
+
+    /// Run the command while pressing the buttons in parallel.
+    pub fn with_buttons<T: Send>(
+        &self,
+        press: impl FnOnce(&Self) + Send,
+        f: impl FnOnce() -> T + Send,
+    ) -> T {
+        thread::scope(|s| {
+            let result = s.spawn(f);
+            press(self);
+            result.join().unwrap()
+        })
+    }
+}
+
+impl Drop for Emulator {
+    fn drop(&mut self) {
+        _ = self.process.kill();
+        _ = self.process.wait();
+    }
+}
+
+/// Send the request to the automation API and return the response body.
+fn request(method: &str, path: &str, body: &str) -> Vec<u8> {
+    let mut stream = TcpStream::connect(("127.0.0.1", API_PORT)).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).unwrap();
+    assert!(
+        response.starts_with(b"HTTP/1.0 200") || response.starts_with(b"HTTP/1.1 200"),
+        "{method} {path} failed"
+    );
+    let body = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .expect("malformed response");
+    response.split_off(body + 4)
+}
+
+/// BIP39 seed of the mnemonic with the empty passphrase.
+fn seed() -> [u8; 64] {
+    let mut seed = [0; 64];
+    pbkdf2::pbkdf2_hmac::<Sha512>(MNEMONIC.as_bytes(), b"mnemonic", 2048, &mut seed);
+    seed
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    for d in data {
+        mac.update(d);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// Derive the private key as the device does for Ed25519 (BIP32-Ed25519
+/// with the `ed25519 seed` root key). Only hardened paths are supported.
+pub fn private_key(path: &Path) -> [u8; 32] {
+    const SEED_KEY: &[u8] = b"ed25519 seed";
+
+    let seed = seed();
+    let mut mac = Hmac::<Sha256>::new_from_slice(SEED_KEY).unwrap();
+    mac.update(&[1]);
+    mac.update(&seed);
+    let mut chain_code: [u8; 32] = mac.finalize().into_bytes().into();
+    let mut key = hmac_sha512(SEED_KEY, &[&seed]);
+    while key[31] & 0x20 != 0 {
+        key = hmac_sha512(SEED_KEY, &[&key]);
+    }
+    key[0] &= 0xf8;
+    key[31] &= 0x7f;
+    key[31] |= 0x40;
+
+    for index in path {
+        assert!(index & 0x80000000 != 0, "only hardened paths are supported");
+        let index = index.to_le_bytes();
+        let z = hmac_sha512(&chain_code, &[&[0], &key, &index]);
+        let i = hmac_sha512(&chain_code, &[&[1], &key, &index]);
+
+        // kL = 8 * zL[..28] + kL
+        let mut carry = 0;
+        for j in 0..32 {
+            let zl = if j < 28 { z[j] as u16 } else { 0 };
+            let sum = key[j] as u16 + (zl << 3) + carry;
+            key[j] = sum as u8;
+            carry = sum >> 8;
+        }
+        // kR = zR + kR mod 2^256
+        let mut carry = 0;
+        for j in 32..64 {
+            let sum = key[j] as u16 + z[j] as u16 + carry;
+            key[j] = sum as u8;
+            carry = sum >> 8;
+        }
+        chain_code.copy_from_slice(&i[32..]);
+    }
+
+    key[..32].try_into().unwrap()
+}
+
+/// Expected public key of the test mnemonic.
+pub fn public_key(scheme: Scheme, path: &Path) -> [u8; 32] {
+    let private_key = private_key(path);
+    match scheme {
+        Scheme::Ed25519 => ed25519_dalek::SigningKey::from_bytes(&private_key)
+            .verifying_key()
+            .to_bytes(),
+        Scheme::Sr25519 => MiniSecretKey::from_bytes(&private_key)
+            .unwrap()
+            .expand(ExpansionMode::Ed25519)
+            .to_public()
+            .to_bytes(),
+    }
+}
+
+/// Check the signature of the message with the expected public key.
+pub fn verify(scheme: Scheme, path: &Path, message: &[u8], signature: &[u8; 64]) {
+    let public_key = public_key(scheme, path);
+    match scheme {
+        Scheme::Ed25519 => {
+            let key = ed25519_dalek::VerifyingKey::from_bytes(&public_key).unwrap();
+            let signature = ed25519_dalek::Signature::from_bytes(signature);
+            key.verify_strict(message, &signature).unwrap();
+        }
+        Scheme::Sr25519 => {
+            let key = schnorrkel::PublicKey::from_bytes(&public_key).unwrap();
+            let signature = schnorrkel::Signature::from_bytes(signature).unwrap();
+            key.verify_simple(b"", message, &signature).unwrap();
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Screenshot tests of the app menus against the golden PNGs in
+//! `tests/golden/<model>`.
+//!
+//! Run with `cargo test -p vara-ledger --test screens -- --ignored` after
+//! building the app for all the models. Set `UPDATE_GOLDEN=1` to write the
+//! current screenshots as the golden ones.
+
+mod common;
+
+use common::{Button, Emulator, Model, PATH};
+use std::{env, fs, path::PathBuf};
+use vara_ledger::Scheme;
+
+/// Screenshots compared with the golden ones.
+struct Screens {
+    dir: PathBuf,
+    update: bool,
+    mismatches: Vec<String>,
+}
+
+impl Screens {
+    fn new(model: Model) -> Self {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(model.name());
+        let update = env::var_os("UPDATE_GOLDEN").is_some_and(|v| v != "0");
+        if update {
+            fs::create_dir_all(&dir).unwrap();
+        }
+        Self {
+            dir,
+            update,
+            mismatches: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, emulator: &Emulator, name: &str) {
+        let screenshot = emulator.screenshot();
+        let path = self.dir.join(name).with_extension("png");
+        if self.update {
+            fs::write(&path, screenshot).unwrap();
+            return;
+        }
+        match fs::read(&path) {
+            Ok(golden) if pixels(&golden) == pixels(&screenshot) => (),
+            Ok(_) => self.mismatches.push(format!("{name}: screen differs")),
+            Err(_) => self
+                .mismatches
+                .push(format!("{name}: no golden screenshot")),
+        }
+    }
+
+    fn finish(self) {
+        assert!(
+            self.mismatches.is_empty(),
+            "screens don't match {} (run with UPDATE_GOLDEN=1 to update):\n{}",
+            self.dir.display(),
+            self.mismatches.join("\n")
+        );
+    }
+}
+
+/// Decode the PNG into its size and RGBA pixels, so the encoding doesn't
+/// matter.
+fn pixels(data: &[u8]) -> (u32, u32, Vec<u8>) {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().unwrap();
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).unwrap();
+    buf.truncate(info.buffer_size());
+    (info.width, info.height, buf)
+}
+
+fn check_screens(model: Model) {
+    let emulator = Emulator::start_model(model);
+    let mut screens = Screens::new(model);
+
+    // Main menu
+    screens.check(&emulator, "main_app_ready");
+    emulator.press(Button::Right);
+    screens.check(&emulator, "main_version");
+    emulator.press(Button::Right);
+    screens.check(&emulator, "main_settings");
+    emulator.press(Button::Right);
+    screens.check(&emulator, "main_quit");
+    emulator.press_all(&[Button::Left, Button::Both]);
+
+    // Settings menu
+    screens.check(&emulator, "settings_blind_signing_disabled");
+    emulator.press(Button::Both);
+    screens.check(&emulator, "settings_blind_signing_enabled");
+    emulator.press(Button::Right);
+    screens.check(&emulator, "settings_back");
+    emulator.press_all(&[Button::Both, Button::Left, Button::Left]);
+
+    // Public key review
+    let mut vara = emulator.client();
+    let result = emulator.with_buttons(
+        |emulator| {
+            screens.check(emulator, "public_key_review");
+            emulator.press(Button::Right);
+            // Address takes several pages on Nano S
+            screens.check(emulator, "public_key_address");
+            emulator.press_until("Accept");
+            screens.check(emulator, "public_key_accept");
+            emulator.press(Button::Right);
+            screens.check(emulator, "public_key_decline");
+            emulator.press(Button::Both);
+        },
+        || vara.get_public_key(Scheme::Sr25519, &PATH, true),
+    );
+    assert!(result.is_err());
+
+    screens.finish();
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn screens_nanos() {
+    check_screens(Model::NanoS);
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn screens_nanosplus() {
+    check_screens(Model::NanoSPlus);
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn screens_nanox() {
+    check_screens(Model::NanoX);
+}
@@ -0,0 +1,250 @@
+//! Integration tests against the app running in Speculos.
+//!
+//! Run with `cargo test -p vara-ledger --test speculos -- --ignored` after
+//! building the app for Nano S Plus, or for the model set in `VARA_MODEL`.
+
+mod common;
+
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use common::{Button, Emulator, PATH};
+use vara_ledger::{transport::Transport, Error, ErrorCode, Scheme};
+
+/// Vara mainnet genesis hash.
+const GENESIS_HASH: &str = "fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763";
+
+/// Signing payload of `system.remark` with the given remark.
+fn remark(remark: &[u8]) -> Vec<u8> {
+    let mut payload = vec![0x00, 0x00];
+    // Compact length
+    match remark.len() {
+        len @ 0..=0x3f => payload.push((len as u8) << 2),
+        len => payload.extend_from_slice(&(((len as u16) << 2) | 0b01).to_le_bytes()),
+    }
+    payload.extend_from_slice(remark);
+    // Immortal era, nonce, tip
+    payload.extend_from_slice(&[0x00, 0x00, 0x00]);
+    // Spec version, transaction version
+    payload.extend_from_slice(&1100u32.to_le_bytes());
+    payload.extend_from_slice(&1u32.to_le_bytes());
+    let genesis_hash = hex::decode(GENESIS_HASH).unwrap();
+    payload.extend_from_slice(&genesis_hash);
+    payload.extend_from_slice(&genesis_hash);
+    payload
+}
+
+fn exchange(emulator: &Emulator, apdu: &str) -> (Vec<u8>, u16) {
+    emulator
+        .transport()
+        .exchange(&hex::decode(apdu).unwrap())
+        .unwrap()
+}
+
+fn assert_device_error<T: std::fmt::Debug>(result: Result<T, Error>, code: ErrorCode) {
+    match result {
+        Err(Error::Device(c)) => assert_eq!(c, code),
+        result => panic!("expected {code:?}, got {result:?}"),
+    }
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn version() {
+    let emulator = Emulator::start();
+    let version = emulator.client().get_version().unwrap();
+    let expected = [
+        env!("CARGO_PKG_VERSION_MAJOR"),
+        env!("CARGO_PKG_VERSION_MINOR"),
+        env!("CARGO_PKG_VERSION_PATCH"),
+    ]
+    .map(|n| n.parse().unwrap());
+    assert_eq!([version.major, version.minor, version.patch], expected);
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn public_key() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    for scheme in [Scheme::Ed25519, Scheme::Sr25519] {
+        for account in 0..2 {
+            let mut path = PATH;
+            path[2] |= account;
+            let public_key = vara.get_public_key(scheme, &path, false).unwrap();
+            assert_eq!(public_key.key, common::public_key(scheme, &path));
+            assert!(public_key.address.starts_with('k'));
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn public_key_without_address() {
+    let emulator = Emulator::start();
+    let (data, status) = exchange(
+        &emulator,
+        "89010000142c00008091030080000000800000008000000080",
+    );
+    assert_eq!(status, 0x9000);
+    assert_eq!(data, common::public_key(Scheme::Ed25519, &PATH));
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn public_key_interactive() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    let public_key = emulator
+        .with_buttons(Emulator::accept, || {
+            vara.get_public_key(Scheme::Sr25519, &PATH, true)
+        })
+        .unwrap();
+    assert_eq!(public_key.key, common::public_key(Scheme::Sr25519, &PATH));
+
+    let result = emulator.with_buttons(Emulator::decline, || {
+        vara.get_public_key(Scheme::Sr25519, &PATH, true)
+    });
+    assert_device_error(result, ErrorCode::UserCancelled);
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn sign() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    let payload = remark(b"Hello");
+    for scheme in [Scheme::Ed25519, Scheme::Sr25519] {
+        let signature = emulator
+            .with_buttons(Emulator::accept, || vara.sign(scheme, &PATH, &payload))
+            .unwrap();
+        common::verify(scheme, &PATH, &payload, &signature);
+    }
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn sign_long_payload() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    let payload = remark(&[0x42; 300]);
+    let hash = Blake2b::<U32>::digest(&payload);
+    for scheme in [Scheme::Ed25519, Scheme::Sr25519] {
+        let signature = emulator
+            .with_buttons(Emulator::accept, || vara.sign(scheme, &PATH, &payload))
+            .unwrap();
+        common::verify(scheme, &PATH, &hash, &signature);
+    }
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn sign_declined() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    let result = emulator.with_buttons(
+        |emulator| {
+            // Review doesn't wrap around to Accept
+            emulator.press_all(&[Button::Left, Button::Left]);
+            assert!(emulator.screen_text().contains("\"Review\""));
+            emulator.decline();
+        },
+        || vara.sign(Scheme::Sr25519, &PATH, &remark(b"Hello")),
+    );
+    assert_device_error(result, ErrorCode::UserCancelled);
+}
+
+#[test]
+#[ignore = "requires Speculos"]
+fn blind_signing() {
+    let emulator = Emulator::start();
+    let mut vara = emulator.client();
+    assert_device_error(
+        vara.sign(Scheme::Sr25519, &PATH, b"Hello"),
+        ErrorCode::BlindSigningDisabled,
+    );
+
+    emulator.toggle_blind_signing();
+    let signature = emulator
+        .with_buttons(Emulator::accept, || {
+            vara.sign(Scheme::Sr25519, &PATH, b"Hello")
+        })
+        .unwrap();
+    common::verify(Scheme::Sr25519, &PATH, b"Hello", &signature);
+}
+
+/// Errors that can be caused by the request. The other error codes are
+/// internal and can't be triggered through APDU.
+#[test]
+#[ignore = "requires Speculos"]
+fn errors() {
+    let emulator = Emulator::start();
+    let cases = [
+        // Empty APDU
+        ("", ErrorCode::NothingReceived),
+        // Unknown CLA
+        ("8000000000", ErrorCode::BadCla),
+        // Unknown INS
+        ("8903000000", ErrorCode::BadIns),
+        // Unknown scheme
+        (
+            "89010200142c00008091030080000000800000008000000080",
+            ErrorCode::BadP1P2,
+        ),
+        // Unknown public key flag
+        (
+            "89010004142c00008091030080000000800000008000000080",
+            ErrorCode::BadP1P2,
+        ),
+        // Unknown sign subcommand
+        ("8902000400", ErrorCode::BadP1P2),
+        // Short path
+        (
+            "89010000102c000080910300800000008000000080",
+            ErrorCode::BadLen,
+        ),
+        // Polkadot path
+        (
+            "89010000142c00008062010080000000800000008000000080",
+            ErrorCode::BadPath,
+        ),
+        (
+            "89020000142c00008062010080000000800000008000000080",
+            ErrorCode::BadPath,
+        ),
+    ];
+    for (apdu, code) in cases {
+        let (_, status) = exchange(&emulator, apdu);
+        assert_eq!(status, code as u16, "APDU {apdu}");
+    }
+
+    // Scheme doesn't match the one of the initialized signing
+    let mut transport = emulator.transport();
+    for (apdu, status) in [
+        ("89020000142c00008091030080000000800000008000000080", 0x9000),
+        ("890201010548656c6c6f", ErrorCode::BadP1P2 as u16),
+        ("8902010200", ErrorCode::BadP1P2 as u16),
+    ] {
+        let (_, s) = transport.exchange(&hex::decode(apdu).unwrap()).unwrap();
+        assert_eq!(s, status, "APDU {apdu}");
+    }
+
+    // Malformed Sails hint and the hint sent after the message
+    for (apdu, status) in [
+        ("89020000142c00008091030080000000800000008000000080", 0x9000),
+        ("8902000303035666", ErrorCode::ParsingError as u16),
+        ("890200010548656c6c6f", 0x9000),
+        (
+            "890200030d03566674085472616e73666572",
+            ErrorCode::BadP1P2 as u16,
+        ),
+    ] {
+        let (_, s) = transport.exchange(&hex::decode(apdu).unwrap()).unwrap();
+        assert_eq!(s, status, "APDU {apdu}");
+    }
+
+    // Signing with the foreign path fails before the review
+    let mut vara = emulator.client();
+    let mut path = PATH;
+    path[1] = 0x80000162;
+    let result = vara.sign(Scheme::Ed25519, &path, &remark(b"Hello"));
+    assert_device_error(result, ErrorCode::BadPath);
+}
@@ -0,0 +1,21 @@
+//! APDU protocol constants, see `docs/apdu.md`.
+
+pub const APDU_CLA: u8 = 0x89;
+
+pub const INS_GET_VERSION: u8 = 0x00;
+pub const INS_GET_PUBLIC_KEY: u8 = 0x01;
+pub const INS_SIGN: u8 = 0x02;
+pub const INS_QUIT: u8 = 0xFF;
+
+/// `INS_SIGN` subcommands.
+pub const COMMAND_START: u8 = 0x00;
+pub const COMMAND_APPEND: u8 = 0x01;
+pub const COMMAND_FINALIZE: u8 = 0x02;
+pub const COMMAND_HINT: u8 = 0x03;
+
+/// `INS_GET_PUBLIC_KEY` flags.
+pub const MODE_INTERACTIVE: u8 = 0x01;
+pub const MODE_ADDRESS: u8 = 0x02;
+
+/// Maximum length of the APDU data.
+pub const MAX_DATA_LEN: usize = 255;
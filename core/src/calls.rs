@@ -0,0 +1,145 @@
+mod balances;
+mod bounties;
+mod child_bounties;
+mod conviction_voting;
+mod gear;
+mod gear_voucher;
+mod identity;
+mod multisig;
+mod nomination_pools;
+mod proxy;
+mod referenda;
+mod session;
+mod staking;
+mod system;
+mod treasury;
+mod utility;
+mod vesting;
+
+/// Vara runtime pallets with the calls known to the decoder.
+pub static PALLETS: &[Pallet] = &[
+    system::PALLET,
+    balances::PALLET,
+    vesting::PALLET,
+    utility::PALLET,
+    staking::PALLET,
+    session::PALLET,
+    identity::PALLET,
+    treasury::PALLET,
+    bounties::PALLET,
+    child_bounties::PALLET,
+    conviction_voting::PALLET,
+    referenda::PALLET,
+    proxy::PALLET,
+    multisig::PALLET,
+    nomination_pools::PALLET,
+    gear::PALLET,
+    gear_voucher::PALLET,
+];
+
+/// Pallet description.
+pub struct Pallet {
+    /// Pallet index in the runtime.
+    pub index: u8,
+    /// Pallet name.
+    pub name: &'static str,
+    /// Known calls.
+    pub calls: &'static [Call],
+}
+
+/// Call description.
+pub struct Call {
+    /// Call index in the pallet.
+    pub index: u8,
+    /// Call name.
+    pub name: &'static str,
+    /// Call arguments.
+    pub args: &'static [Arg],
+}
+
+/// Named argument or field.
+pub struct Arg {
+    /// Name shown to the user.
+    pub name: &'static str,
+    /// Argument type.
+    pub ty: Ty,
+}
+
+/// Enum variant description.
+pub struct Variant {
+    /// Variant index.
+    pub index: u8,
+    /// Variant name shown to the user.
+    pub name: &'static str,
+    /// Variant fields.
+    pub fields: &'static [Arg],
+}
+
+/// Type of the argument.
+#[derive(Clone, Copy)]
+pub enum Ty {
+    /// `Vec<u8>`.
+    Bytes,
+    /// `Vec<u8>` always shown as its length and Blake2b-256 hash.
+    Hashed,
+    /// `Vec<u8>` UTF-8 text. Long text is shown as its length and
+    /// Blake2b-256 hash.
+    Text,
+    /// `Vec<u8>` message payload, decoded with the Sails hint if it is
+    /// given.
+    Payload,
+    /// `bool`.
+    Bool,
+    /// `u16`.
+    U16,
+    /// `u32`.
+    U32,
+    /// `u64`.
+    U64,
+    /// Compact-encoded integer.
+    Compact,
+    /// `u32` number of blocks.
+    Blocks,
+    /// `Compact<u128>` amount of VARA.
+    Balance,
+    /// `u128` amount of VARA.
+    FixedBalance,
+    /// `AccountId32`.
+    AccountId,
+    /// 32-byte identifier shown in hex format, e.g. `ActorId`.
+    H256,
+    /// 20-byte value shown in hex format.
+    H160,
+    /// Identity `Data`: text if it is valid UTF-8, otherwise hex or hash.
+    Data,
+    /// `MultiAddress<AccountId32, u32>`.
+    Address,
+    /// Nested runtime call, its arguments are shown after its name.
+    Call,
+    /// Nested runtime call followed by its Blake2b-256 hash.
+    HashedCall,
+    /// Conviction vote: aye or nay with the conviction.
+    Vote,
+    /// Vote conviction.
+    Conviction,
+    /// Multisig `Timepoint`: block height and extrinsic index.
+    Timepoint,
+    /// `Weight`: compact reference time and proof size.
+    Weight,
+    /// `Option<T>`, `None` is shown as is.
+    Option(&'static Ty),
+    /// Tuple, the first field is shown with the label of the tuple and the
+    /// others with their names.
+    Tuple(&'static [Arg]),
+    /// `Vec<T>`, items are labeled as `Name i of n`.
+    Vec(&'static Ty),
+    /// Enum, the variant name is shown followed by its fields.
+    Enum(&'static [Variant]),
+}
+
+/// Find the call by the pallet and call indices.
+pub fn find(pallet_index: u8, call_index: u8) -> Option<(&'static Pallet, &'static Call)> {
+    let pallet = PALLETS.iter().find(|p| p.index == pallet_index)?;
+    let call = pallet.calls.iter().find(|c| c.index == call_index)?;
+    Some((pallet, call))
+}
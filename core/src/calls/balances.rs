@@ -0,0 +1,39 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const DEST: Arg = Arg {
+    name: "Dest",
+    ty: Ty::Address,
+};
+
+const AMOUNT: Arg = Arg {
+    name: "Amount",
+    ty: Ty::Balance,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 5,
+    name: "balances",
+    calls: &[
+        Call {
+            index: 0,
+            name: "transfer_allow_death",
+            args: &[DEST, AMOUNT],
+        },
+        Call {
+            index: 3,
+            name: "transfer_keep_alive",
+            args: &[DEST, AMOUNT],
+        },
+        Call {
+            index: 4,
+            name: "transfer_all",
+            args: &[
+                DEST,
+                Arg {
+                    name: "Keep alive",
+                    ty: Ty::Bool,
+                },
+            ],
+        },
+    ],
+};
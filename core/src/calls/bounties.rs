@@ -0,0 +1,84 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const BOUNTY: Arg = Arg {
+    name: "Bounty",
+    ty: Ty::Compact,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 29,
+    name: "bounties",
+    calls: &[
+        Call {
+            index: 0,
+            name: "propose_bounty",
+            args: &[
+                Arg {
+                    name: "Value",
+                    ty: Ty::Balance,
+                },
+                Arg {
+                    name: "Description",
+                    ty: Ty::Text,
+                },
+            ],
+        },
+        Call {
+            index: 2,
+            name: "propose_curator",
+            args: &[
+                BOUNTY,
+                Arg {
+                    name: "Curator",
+                    ty: Ty::Address,
+                },
+                Arg {
+                    name: "Fee",
+                    ty: Ty::Balance,
+                },
+            ],
+        },
+        Call {
+            index: 3,
+            name: "unassign_curator",
+            args: &[BOUNTY],
+        },
+        Call {
+            index: 4,
+            name: "accept_curator",
+            args: &[BOUNTY],
+        },
+        Call {
+            index: 5,
+            name: "award_bounty",
+            args: &[
+                BOUNTY,
+                Arg {
+                    name: "Beneficiary",
+                    ty: Ty::Address,
+                },
+            ],
+        },
+        Call {
+            index: 6,
+            name: "claim_bounty",
+            args: &[BOUNTY],
+        },
+        Call {
+            index: 7,
+            name: "close_bounty",
+            args: &[BOUNTY],
+        },
+        Call {
+            index: 8,
+            name: "extend_bounty_expiry",
+            args: &[
+                BOUNTY,
+                Arg {
+                    name: "Remark",
+                    ty: Ty::Text,
+                },
+            ],
+        },
+    ],
+};
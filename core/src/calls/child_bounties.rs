@@ -0,0 +1,81 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const PARENT: Arg = Arg {
+    name: "Parent bounty",
+    ty: Ty::Compact,
+};
+
+const CHILD: Arg = Arg {
+    name: "Child bounty",
+    ty: Ty::Compact,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 30,
+    name: "child_bounties",
+    calls: &[
+        Call {
+            index: 0,
+            name: "add_child_bounty",
+            args: &[
+                PARENT,
+                Arg {
+                    name: "Value",
+                    ty: Ty::Balance,
+                },
+                Arg {
+                    name: "Description",
+                    ty: Ty::Text,
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "propose_curator",
+            args: &[
+                PARENT,
+                CHILD,
+                Arg {
+                    name: "Curator",
+                    ty: Ty::Address,
+                },
+                Arg {
+                    name: "Fee",
+                    ty: Ty::Balance,
+                },
+            ],
+        },
+        Call {
+            index: 2,
+            name: "accept_curator",
+            args: &[PARENT, CHILD],
+        },
+        Call {
+            index: 3,
+            name: "unassign_curator",
+            args: &[PARENT, CHILD],
+        },
+        Call {
+            index: 4,
+            name: "award_child_bounty",
+            args: &[
+                PARENT,
+                CHILD,
+                Arg {
+                    name: "Beneficiary",
+                    ty: Ty::Address,
+                },
+            ],
+        },
+        Call {
+            index: 5,
+            name: "claim_child_bounty",
+            args: &[PARENT, CHILD],
+        },
+        Call {
+            index: 6,
+            name: "close_child_bounty",
+            args: &[PARENT, CHILD],
+        },
+    ],
+};
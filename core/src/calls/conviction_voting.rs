@@ -0,0 +1,129 @@
+use super::{Arg, Call, Pallet, Ty, Variant};
+
+const TRACK: Arg = Arg {
+    name: "Track",
+    ty: Ty::U16,
+};
+
+const TARGET: Arg = Arg {
+    name: "Target",
+    ty: Ty::Address,
+};
+
+const AYE: Arg = Arg {
+    name: "Aye",
+    ty: Ty::FixedBalance,
+};
+
+const NAY: Arg = Arg {
+    name: "Nay",
+    ty: Ty::FixedBalance,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 16,
+    name: "conviction_voting",
+    calls: &[
+        Call {
+            index: 0,
+            name: "vote",
+            args: &[
+                Arg {
+                    name: "Referendum",
+                    ty: Ty::Compact,
+                },
+                Arg {
+                    name: "Vote type",
+                    ty: Ty::Enum(&[
+                        Variant {
+                            index: 0,
+                            name: "Standard",
+                            fields: &[
+                                Arg {
+                                    name: "Vote",
+                                    ty: Ty::Vote,
+                                },
+                                Arg {
+                                    name: "Balance",
+                                    ty: Ty::FixedBalance,
+                                },
+                            ],
+                        },
+                        Variant {
+                            index: 1,
+                            name: "Split",
+                            fields: &[AYE, NAY],
+                        },
+                        Variant {
+                            index: 2,
+                            name: "Split abstain",
+                            fields: &[
+                                AYE,
+                                NAY,
+                                Arg {
+                                    name: "Abstain",
+                                    ty: Ty::FixedBalance,
+                                },
+                            ],
+                        },
+                    ]),
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "delegate",
+            args: &[
+                TRACK,
+                Arg {
+                    name: "To",
+                    ty: Ty::Address,
+                },
+                Arg {
+                    name: "Conviction",
+                    ty: Ty::Conviction,
+                },
+                Arg {
+                    name: "Balance",
+                    ty: Ty::FixedBalance,
+                },
+            ],
+        },
+        Call {
+            index: 2,
+            name: "undelegate",
+            args: &[TRACK],
+        },
+        Call {
+            index: 3,
+            name: "unlock",
+            args: &[TRACK, TARGET],
+        },
+        Call {
+            index: 4,
+            name: "remove_vote",
+            args: &[
+                Arg {
+                    name: "Track",
+                    ty: Ty::Option(&Ty::U16),
+                },
+                Arg {
+                    name: "Referendum",
+                    ty: Ty::U32,
+                },
+            ],
+        },
+        Call {
+            index: 5,
+            name: "remove_other_vote",
+            args: &[
+                TARGET,
+                TRACK,
+                Arg {
+                    name: "Referendum",
+                    ty: Ty::U32,
+                },
+            ],
+        },
+    ],
+};
@@ -0,0 +1,115 @@
+use super::{Arg, Call, Pallet, Ty};
+
+/// Program code. Its hash is the code id.
+const CODE: Arg = Arg {
+    name: "Code",
+    ty: Ty::Hashed,
+};
+
+const SALT: Arg = Arg {
+    name: "Salt",
+    ty: Ty::Bytes,
+};
+
+const PAYLOAD: Arg = Arg {
+    name: "Payload",
+    ty: Ty::Bytes,
+};
+
+/// Payload of the message to a program.
+const MESSAGE_PAYLOAD: Arg = Arg {
+    name: "Payload",
+    ty: Ty::Payload,
+};
+
+const GAS_LIMIT: Arg = Arg {
+    name: "Gas limit",
+    ty: Ty::U64,
+};
+
+const VALUE: Arg = Arg {
+    name: "Value",
+    ty: Ty::FixedBalance,
+};
+
+const KEEP_ALIVE: Arg = Arg {
+    name: "Keep alive",
+    ty: Ty::Bool,
+};
+
+/// Arguments of `send_message`, also used in voucher calls.
+pub const SEND_MESSAGE: &[Arg] = &[
+    Arg {
+        name: "Destination",
+        ty: Ty::H256,
+    },
+    MESSAGE_PAYLOAD,
+    GAS_LIMIT,
+    VALUE,
+    KEEP_ALIVE,
+];
+
+/// Arguments of `send_reply`, also used in voucher calls.
+pub const SEND_REPLY: &[Arg] = &[
+    Arg {
+        name: "Reply to",
+        ty: Ty::H256,
+    },
+    PAYLOAD,
+    GAS_LIMIT,
+    VALUE,
+    KEEP_ALIVE,
+];
+
+/// Arguments of `upload_code`, also used in voucher calls.
+pub const UPLOAD_CODE: &[Arg] = &[CODE];
+
+pub const PALLET: Pallet = Pallet {
+    index: 104,
+    name: "gear",
+    calls: &[
+        Call {
+            index: 0,
+            name: "upload_code",
+            args: UPLOAD_CODE,
+        },
+        Call {
+            index: 1,
+            name: "upload_program",
+            args: &[CODE, SALT, PAYLOAD, GAS_LIMIT, VALUE, KEEP_ALIVE],
+        },
+        Call {
+            index: 2,
+            name: "create_program",
+            args: &[
+                Arg {
+                    name: "Code id",
+                    ty: Ty::H256,
+                },
+                SALT,
+                PAYLOAD,
+                GAS_LIMIT,
+                VALUE,
+                KEEP_ALIVE,
+            ],
+        },
+        Call {
+            index: 3,
+            name: "send_message",
+            args: SEND_MESSAGE,
+        },
+        Call {
+            index: 4,
+            name: "send_reply",
+            args: SEND_REPLY,
+        },
+        Call {
+            index: 5,
+            name: "claim_value",
+            args: &[Arg {
+                name: "Message id",
+                ty: Ty::H256,
+            }],
+        },
+    ],
+};
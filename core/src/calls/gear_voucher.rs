@@ -0,0 +1,132 @@
+use super::{gear, Arg, Call, Pallet, Ty, Variant};
+
+const SPENDER: Arg = Arg {
+    name: "Spender",
+    ty: Ty::AccountId,
+};
+
+const VOUCHER: Arg = Arg {
+    name: "Voucher",
+    ty: Ty::H256,
+};
+
+/// Programs the voucher can be used for, `None` allows any program.
+const PROGRAMS: Ty = Ty::Enum(&[
+    Variant {
+        index: 0,
+        name: "Any",
+        fields: &[],
+    },
+    Variant {
+        index: 1,
+        name: "Listed",
+        fields: &[Arg {
+            name: "Program",
+            ty: Ty::Vec(&Ty::H256),
+        }],
+    },
+]);
+
+const PREPAID_CALL: Arg = Arg {
+    name: "Prepaid call",
+    ty: Ty::Enum(&[
+        Variant {
+            index: 0,
+            name: "gear.send_message",
+            fields: gear::SEND_MESSAGE,
+        },
+        Variant {
+            index: 1,
+            name: "gear.send_reply",
+            fields: gear::SEND_REPLY,
+        },
+        Variant {
+            index: 2,
+            name: "gear.upload_code",
+            fields: gear::UPLOAD_CODE,
+        },
+        Variant {
+            index: 3,
+            name: "gear_voucher.decline",
+            fields: &[],
+        },
+    ]),
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 107,
+    name: "gear_voucher",
+    calls: &[
+        Call {
+            index: 0,
+            name: "issue",
+            args: &[
+                SPENDER,
+                Arg {
+                    name: "Balance",
+                    ty: Ty::FixedBalance,
+                },
+                Arg {
+                    name: "Programs",
+                    ty: PROGRAMS,
+                },
+                Arg {
+                    name: "Code uploading",
+                    ty: Ty::Bool,
+                },
+                Arg {
+                    name: "Duration",
+                    ty: Ty::Blocks,
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "call",
+            args: &[VOUCHER, PREPAID_CALL],
+        },
+        Call {
+            index: 2,
+            name: "revoke",
+            args: &[SPENDER, VOUCHER],
+        },
+        Call {
+            index: 3,
+            name: "update",
+            args: &[
+                SPENDER,
+                VOUCHER,
+                Arg {
+                    name: "New owner",
+                    ty: Ty::Option(&Ty::AccountId),
+                },
+                Arg {
+                    name: "Top up",
+                    ty: Ty::Option(&Ty::FixedBalance),
+                },
+                Arg {
+                    name: "Add programs",
+                    ty: Ty::Option(&PROGRAMS),
+                },
+                Arg {
+                    name: "Code uploading",
+                    ty: Ty::Option(&Ty::Bool),
+                },
+                Arg {
+                    name: "Prolong duration",
+                    ty: Ty::Option(&Ty::Blocks),
+                },
+            ],
+        },
+        Call {
+            index: 4,
+            name: "call_deprecated",
+            args: &[PREPAID_CALL],
+        },
+        Call {
+            index: 5,
+            name: "decline",
+            args: &[VOUCHER],
+        },
+    ],
+};
@@ -0,0 +1,105 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const SUB: Arg = Arg {
+    name: "Sub",
+    ty: Ty::Address,
+};
+
+const SUB_NAME: Arg = Arg {
+    name: "Name",
+    ty: Ty::Data,
+};
+
+const fn data(name: &'static str) -> Arg {
+    Arg { name, ty: Ty::Data }
+}
+
+pub const PALLET: Pallet = Pallet {
+    index: 24,
+    name: "identity",
+    calls: &[
+        Call {
+            index: 1,
+            name: "set_identity",
+            // `IdentityInfo` fields
+            args: &[
+                Arg {
+                    name: "Additional",
+                    ty: Ty::Vec(&Ty::Tuple(&[data("Additional"), data("Value")])),
+                },
+                data("Display"),
+                data("Legal"),
+                data("Web"),
+                data("Riot"),
+                data("Email"),
+                Arg {
+                    name: "PGP fingerprint",
+                    ty: Ty::Option(&Ty::H160),
+                },
+                data("Image"),
+                data("Twitter"),
+            ],
+        },
+        Call {
+            index: 2,
+            name: "set_subs",
+            args: &[Arg {
+                name: "Sub",
+                ty: Ty::Vec(&Ty::Tuple(&[
+                    Arg {
+                        name: "Sub",
+                        ty: Ty::AccountId,
+                    },
+                    SUB_NAME,
+                ])),
+            }],
+        },
+        Call {
+            index: 3,
+            name: "clear_identity",
+            args: &[],
+        },
+        Call {
+            index: 4,
+            name: "request_judgement",
+            args: &[
+                Arg {
+                    name: "Registrar",
+                    ty: Ty::Compact,
+                },
+                Arg {
+                    name: "Max fee",
+                    ty: Ty::Balance,
+                },
+            ],
+        },
+        Call {
+            index: 5,
+            name: "cancel_request",
+            args: &[Arg {
+                name: "Registrar",
+                ty: Ty::U32,
+            }],
+        },
+        Call {
+            index: 11,
+            name: "add_sub",
+            args: &[SUB, SUB_NAME],
+        },
+        Call {
+            index: 12,
+            name: "rename_sub",
+            args: &[SUB, SUB_NAME],
+        },
+        Call {
+            index: 13,
+            name: "remove_sub",
+            args: &[SUB],
+        },
+        Call {
+            index: 14,
+            name: "quit_sub",
+            args: &[],
+        },
+    ],
+};
@@ -0,0 +1,82 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const THRESHOLD: Arg = Arg {
+    name: "Threshold",
+    ty: Ty::U16,
+};
+
+const OTHER_SIGNATORIES: Arg = Arg {
+    name: "Signatory",
+    ty: Ty::Vec(&Ty::AccountId),
+};
+
+const MAYBE_TIMEPOINT: Arg = Arg {
+    name: "Timepoint",
+    ty: Ty::Option(&Ty::Timepoint),
+};
+
+const CALL_HASH: Arg = Arg {
+    name: "Call hash",
+    ty: Ty::H256,
+};
+
+const MAX_WEIGHT: Arg = Arg {
+    name: "Max weight",
+    ty: Ty::Weight,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 26,
+    name: "multisig",
+    calls: &[
+        Call {
+            index: 0,
+            name: "as_multi_threshold_1",
+            args: &[
+                OTHER_SIGNATORIES,
+                Arg {
+                    name: "Call",
+                    ty: Ty::Call,
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "as_multi",
+            args: &[
+                THRESHOLD,
+                OTHER_SIGNATORIES,
+                MAYBE_TIMEPOINT,
+                Arg {
+                    name: "Call",
+                    ty: Ty::HashedCall,
+                },
+                MAX_WEIGHT,
+            ],
+        },
+        Call {
+            index: 2,
+            name: "approve_as_multi",
+            args: &[
+                THRESHOLD,
+                OTHER_SIGNATORIES,
+                MAYBE_TIMEPOINT,
+                CALL_HASH,
+                MAX_WEIGHT,
+            ],
+        },
+        Call {
+            index: 3,
+            name: "cancel_as_multi",
+            args: &[
+                THRESHOLD,
+                OTHER_SIGNATORIES,
+                Arg {
+                    name: "Timepoint",
+                    ty: Ty::Timepoint,
+                },
+                CALL_HASH,
+            ],
+        },
+    ],
+};
@@ -0,0 +1,113 @@
+use super::{Arg, Call, Pallet, Ty, Variant};
+
+const POOL: Arg = Arg {
+    name: "Pool",
+    ty: Ty::U32,
+};
+
+const MEMBER: Arg = Arg {
+    name: "Member",
+    ty: Ty::Address,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 31,
+    name: "nomination_pools",
+    calls: &[
+        Call {
+            index: 0,
+            name: "join",
+            args: &[
+                Arg {
+                    name: "Amount",
+                    ty: Ty::Balance,
+                },
+                POOL,
+            ],
+        },
+        Call {
+            index: 1,
+            name: "bond_extra",
+            args: &[Arg {
+                name: "Extra",
+                ty: Ty::Enum(&[
+                    Variant {
+                        index: 0,
+                        name: "Free balance",
+                        fields: &[Arg {
+                            name: "Amount",
+                            ty: Ty::FixedBalance,
+                        }],
+                    },
+                    Variant {
+                        index: 1,
+                        name: "Rewards",
+                        fields: &[],
+                    },
+                ]),
+            }],
+        },
+        Call {
+            index: 2,
+            name: "claim_payout",
+            args: &[],
+        },
+        Call {
+            index: 3,
+            name: "unbond",
+            args: &[
+                MEMBER,
+                // Pool points rather than the balance
+                Arg {
+                    name: "Unbonding points",
+                    ty: Ty::Compact,
+                },
+            ],
+        },
+        Call {
+            index: 5,
+            name: "withdraw_unbonded",
+            args: &[
+                MEMBER,
+                Arg {
+                    name: "Slashing spans",
+                    ty: Ty::U32,
+                },
+            ],
+        },
+        Call {
+            index: 15,
+            name: "set_claim_permission",
+            args: &[Arg {
+                name: "Permission",
+                ty: Ty::Enum(&[
+                    Variant {
+                        index: 0,
+                        name: "Permissioned",
+                        fields: &[],
+                    },
+                    Variant {
+                        index: 1,
+                        name: "Permissionless compound",
+                        fields: &[],
+                    },
+                    Variant {
+                        index: 2,
+                        name: "Permissionless withdraw",
+                        fields: &[],
+                    },
+                    Variant {
+                        index: 3,
+                        name: "Permissionless all",
+                        fields: &[],
+                    },
+                ]),
+            }],
+        },
+        Call {
+            index: 20,
+            name: "claim_commission",
+            args: &[POOL],
+        },
+    ],
+};
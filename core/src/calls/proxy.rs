@@ -0,0 +1,136 @@
+use super::{Arg, Call, Pallet, Ty, Variant};
+
+const PROXY_TYPE: Ty = Ty::Enum(&[
+    Variant {
+        index: 0,
+        name: "Any",
+        fields: &[],
+    },
+    Variant {
+        index: 1,
+        name: "Non transfer",
+        fields: &[],
+    },
+    Variant {
+        index: 2,
+        name: "Governance",
+        fields: &[],
+    },
+    Variant {
+        index: 3,
+        name: "Staking",
+        fields: &[],
+    },
+    Variant {
+        index: 4,
+        name: "Identity judgement",
+        fields: &[],
+    },
+    Variant {
+        index: 5,
+        name: "Cancel proxy",
+        fields: &[],
+    },
+]);
+
+const REAL: Arg = Arg {
+    name: "Real",
+    ty: Ty::Address,
+};
+
+const DELEGATE: Arg = Arg {
+    name: "Delegate",
+    ty: Ty::Address,
+};
+
+const FORCE_PROXY_TYPE: Arg = Arg {
+    name: "Force type",
+    ty: Ty::Option(&PROXY_TYPE),
+};
+
+const CALL: Arg = Arg {
+    name: "Call",
+    ty: Ty::Call,
+};
+
+const TYPE: Arg = Arg {
+    name: "Proxy type",
+    ty: PROXY_TYPE,
+};
+
+const DELAY: Arg = Arg {
+    name: "Delay",
+    ty: Ty::Blocks,
+};
+
+const INDEX: Arg = Arg {
+    name: "Index",
+    ty: Ty::U16,
+};
+
+const CALL_HASH: Arg = Arg {
+    name: "Call hash",
+    ty: Ty::H256,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 25,
+    name: "proxy",
+    calls: &[
+        Call {
+            index: 0,
+            name: "proxy",
+            args: &[REAL, FORCE_PROXY_TYPE, CALL],
+        },
+        Call {
+            index: 1,
+            name: "add_proxy",
+            args: &[DELEGATE, TYPE, DELAY],
+        },
+        Call {
+            index: 2,
+            name: "remove_proxy",
+            args: &[DELEGATE, TYPE, DELAY],
+        },
+        Call {
+            index: 3,
+            name: "remove_proxies",
+            args: &[],
+        },
+        Call {
+            index: 4,
+            name: "create_pure",
+            args: &[TYPE, DELAY, INDEX],
+        },
+        Call {
+            index: 5,
+            name: "kill_pure",
+            args: &[
+                Arg {
+                    name: "Spawner",
+                    ty: Ty::Address,
+                },
+                TYPE,
+                INDEX,
+                Arg {
+                    name: "Height",
+                    ty: Ty::Compact,
+                },
+                Arg {
+                    name: "Extrinsic index",
+                    ty: Ty::Compact,
+                },
+            ],
+        },
+        Call {
+            index: 6,
+            name: "announce",
+            args: &[REAL, CALL_HASH],
+        },
+        Call {
+            index: 9,
+            name: "proxy_announced",
+            args: &[DELEGATE, REAL, FORCE_PROXY_TYPE, CALL],
+        },
+    ],
+};
@@ -0,0 +1,198 @@
+use super::{Arg, Call, Pallet, Ty, Variant};
+
+const REFERENDUM: Arg = Arg {
+    name: "Referendum",
+    ty: Ty::U32,
+};
+
+const HASH: Arg = Arg {
+    name: "Hash",
+    ty: Ty::H256,
+};
+
+/// `OriginCaller` of the runtime: `system` and the governance origins.
+const ORIGIN: Ty = Ty::Enum(&[
+    Variant {
+        index: 0,
+        name: "System",
+        fields: &[Arg {
+            name: "System origin",
+            ty: Ty::Enum(&[
+                Variant {
+                    index: 0,
+                    name: "Root",
+                    fields: &[],
+                },
+                Variant {
+                    index: 1,
+                    name: "Signed",
+                    fields: &[Arg {
+                        name: "Account",
+                        ty: Ty::AccountId,
+                    }],
+                },
+                Variant {
+                    index: 2,
+                    name: "None",
+                    fields: &[],
+                },
+            ]),
+        }],
+    },
+    Variant {
+        index: 20,
+        name: "Origins",
+        fields: &[Arg {
+            name: "Track origin",
+            ty: Ty::Enum(&[
+                Variant {
+                    index: 0,
+                    name: "Staking admin",
+                    fields: &[],
+                },
+                Variant {
+                    index: 1,
+                    name: "Treasurer",
+                    fields: &[],
+                },
+                Variant {
+                    index: 2,
+                    name: "Fellowship admin",
+                    fields: &[],
+                },
+                Variant {
+                    index: 3,
+                    name: "General admin",
+                    fields: &[],
+                },
+                Variant {
+                    index: 4,
+                    name: "Referendum canceller",
+                    fields: &[],
+                },
+                Variant {
+                    index: 5,
+                    name: "Referendum killer",
+                    fields: &[],
+                },
+                Variant {
+                    index: 6,
+                    name: "Small tipper",
+                    fields: &[],
+                },
+                Variant {
+                    index: 7,
+                    name: "Big tipper",
+                    fields: &[],
+                },
+                Variant {
+                    index: 8,
+                    name: "Small spender",
+                    fields: &[],
+                },
+                Variant {
+                    index: 9,
+                    name: "Medium spender",
+                    fields: &[],
+                },
+                Variant {
+                    index: 10,
+                    name: "Big spender",
+                    fields: &[],
+                },
+                Variant {
+                    index: 11,
+                    name: "Whitelisted caller",
+                    fields: &[],
+                },
+            ]),
+        }],
+    },
+]);
+
+/// `Bounded<Call>`: the proposal call is shown as its hash or bytes.
+const PROPOSAL: Ty = Ty::Enum(&[
+    Variant {
+        index: 0,
+        name: "Legacy",
+        fields: &[HASH],
+    },
+    Variant {
+        index: 1,
+        name: "Inline",
+        fields: &[Arg {
+            name: "Call",
+            ty: Ty::Bytes,
+        }],
+    },
+    Variant {
+        index: 2,
+        name: "Lookup",
+        fields: &[
+            HASH,
+            Arg {
+                name: "Length",
+                ty: Ty::U32,
+            },
+        ],
+    },
+]);
+
+const ENACTMENT: Ty = Ty::Enum(&[
+    Variant {
+        index: 0,
+        name: "At",
+        fields: &[Arg {
+            name: "Block",
+            ty: Ty::U32,
+        }],
+    },
+    Variant {
+        index: 1,
+        name: "After",
+        fields: &[Arg {
+            name: "Delay",
+            ty: Ty::Blocks,
+        }],
+    },
+]);
+
+pub const PALLET: Pallet = Pallet {
+    index: 17,
+    name: "referenda",
+    calls: &[
+        Call {
+            index: 0,
+            name: "submit",
+            args: &[
+                Arg {
+                    name: "Origin",
+                    ty: ORIGIN,
+                },
+                Arg {
+                    name: "Proposal",
+                    ty: PROPOSAL,
+                },
+                Arg {
+                    name: "Enactment",
+                    ty: ENACTMENT,
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "place_decision_deposit",
+            args: &[REFERENDUM],
+        },
+        Call {
+            index: 2,
+            name: "refund_decision_deposit",
+            args: &[REFERENDUM],
+        },
+        Call {
+            index: 7,
+            name: "refund_submission_deposit",
+            args: &[REFERENDUM],
+        },
+    ],
+};
@@ -0,0 +1,40 @@
+use super::{Arg, Call, Pallet, Ty};
+
+pub const PALLET: Pallet = Pallet {
+    index: 7,
+    name: "session",
+    calls: &[
+        Call {
+            index: 0,
+            name: "set_keys",
+            // `SessionKeys` fields followed by the proof
+            args: &[
+                Arg {
+                    name: "Babe key",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "Grandpa key",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "ImOnline key",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "Authority discovery key",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "Proof",
+                    ty: Ty::Hashed,
+                },
+            ],
+        },
+        Call {
+            index: 1,
+            name: "purge_keys",
+            args: &[],
+        },
+    ],
+};
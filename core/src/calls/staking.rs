@@ -0,0 +1,107 @@
+use super::{Arg, Call, Pallet, Ty, Variant};
+
+const VALUE: Arg = Arg {
+    name: "Amount",
+    ty: Ty::Balance,
+};
+
+const PAYEE: Arg = Arg {
+    name: "Payee",
+    ty: Ty::Enum(&[
+        Variant {
+            index: 0,
+            name: "Staked",
+            fields: &[],
+        },
+        Variant {
+            index: 1,
+            name: "Stash",
+            fields: &[],
+        },
+        Variant {
+            index: 2,
+            name: "Controller",
+            fields: &[],
+        },
+        Variant {
+            index: 3,
+            name: "Account",
+            fields: &[Arg {
+                name: "Payee account",
+                ty: Ty::AccountId,
+            }],
+        },
+        Variant {
+            index: 4,
+            name: "None",
+            fields: &[],
+        },
+    ]),
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 13,
+    name: "staking",
+    calls: &[
+        Call {
+            index: 0,
+            name: "bond",
+            args: &[VALUE, PAYEE],
+        },
+        Call {
+            index: 1,
+            name: "bond_extra",
+            args: &[VALUE],
+        },
+        Call {
+            index: 2,
+            name: "unbond",
+            args: &[VALUE],
+        },
+        Call {
+            index: 3,
+            name: "withdraw_unbonded",
+            args: &[Arg {
+                name: "Slashing spans",
+                ty: Ty::U32,
+            }],
+        },
+        Call {
+            index: 5,
+            name: "nominate",
+            args: &[Arg {
+                name: "Target",
+                ty: Ty::Vec(&Ty::Address),
+            }],
+        },
+        Call {
+            index: 6,
+            name: "chill",
+            args: &[],
+        },
+        Call {
+            index: 7,
+            name: "set_payee",
+            args: &[PAYEE],
+        },
+        Call {
+            index: 18,
+            name: "payout_stakers",
+            args: &[
+                Arg {
+                    name: "Validator",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "Era",
+                    ty: Ty::U32,
+                },
+            ],
+        },
+        Call {
+            index: 19,
+            name: "rebond",
+            args: &[VALUE],
+        },
+    ],
+};
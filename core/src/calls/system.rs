@@ -0,0 +1,24 @@
+use super::{Arg, Call, Pallet, Ty};
+
+pub const PALLET: Pallet = Pallet {
+    index: 0,
+    name: "system",
+    calls: &[
+        Call {
+            index: 0,
+            name: "remark",
+            args: &[Arg {
+                name: "Remark",
+                ty: Ty::Bytes,
+            }],
+        },
+        Call {
+            index: 7,
+            name: "remark_with_event",
+            args: &[Arg {
+                name: "Remark",
+                ty: Ty::Bytes,
+            }],
+        },
+    ],
+};
@@ -0,0 +1,69 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const INDEX: Arg = Arg {
+    name: "Spend",
+    ty: Ty::U32,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 14,
+    name: "treasury",
+    calls: &[
+        Call {
+            index: 3,
+            name: "spend_local",
+            args: &[
+                Arg {
+                    name: "Amount",
+                    ty: Ty::Balance,
+                },
+                Arg {
+                    name: "Beneficiary",
+                    ty: Ty::Address,
+                },
+            ],
+        },
+        Call {
+            index: 4,
+            name: "remove_approval",
+            args: &[Arg {
+                name: "Proposal",
+                ty: Ty::Compact,
+            }],
+        },
+        Call {
+            index: 5,
+            name: "spend",
+            // The asset kind is the native token and is encoded as nothing
+            args: &[
+                Arg {
+                    name: "Amount",
+                    ty: Ty::Balance,
+                },
+                Arg {
+                    name: "Beneficiary",
+                    ty: Ty::AccountId,
+                },
+                Arg {
+                    name: "Valid from",
+                    ty: Ty::Option(&Ty::U32),
+                },
+            ],
+        },
+        Call {
+            index: 6,
+            name: "payout",
+            args: &[INDEX],
+        },
+        Call {
+            index: 7,
+            name: "check_status",
+            args: &[INDEX],
+        },
+        Call {
+            index: 8,
+            name: "void_spend",
+            args: &[INDEX],
+        },
+    ],
+};
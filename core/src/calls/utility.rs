@@ -0,0 +1,42 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const CALLS: Arg = Arg {
+    name: "Call",
+    ty: Ty::Vec(&Ty::Call),
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 8,
+    name: "utility",
+    calls: &[
+        Call {
+            index: 0,
+            name: "batch",
+            args: &[CALLS],
+        },
+        Call {
+            index: 1,
+            name: "as_derivative",
+            args: &[
+                Arg {
+                    name: "Derivative index",
+                    ty: Ty::U16,
+                },
+                Arg {
+                    name: "Call",
+                    ty: Ty::Call,
+                },
+            ],
+        },
+        Call {
+            index: 2,
+            name: "batch_all",
+            args: &[CALLS],
+        },
+        Call {
+            index: 4,
+            name: "force_batch",
+            args: &[CALLS],
+        },
+    ],
+};
@@ -0,0 +1,70 @@
+use super::{Arg, Call, Pallet, Ty};
+
+const TARGET: Arg = Arg {
+    name: "Target",
+    ty: Ty::Address,
+};
+
+// `VestingInfo` fields
+const LOCKED: Arg = Arg {
+    name: "Locked",
+    ty: Ty::FixedBalance,
+};
+const PER_BLOCK: Arg = Arg {
+    name: "Per block",
+    ty: Ty::FixedBalance,
+};
+const STARTING_BLOCK: Arg = Arg {
+    name: "Starting block",
+    ty: Ty::U32,
+};
+
+pub const PALLET: Pallet = Pallet {
+    index: 10,
+    name: "vesting",
+    calls: &[
+        Call {
+            index: 0,
+            name: "vest",
+            args: &[],
+        },
+        Call {
+            index: 1,
+            name: "vest_other",
+            args: &[TARGET],
+        },
+        Call {
+            index: 2,
+            name: "vested_transfer",
+            args: &[TARGET, LOCKED, PER_BLOCK, STARTING_BLOCK],
+        },
+        Call {
+            index: 3,
+            name: "force_vested_transfer",
+            args: &[
+                Arg {
+                    name: "Source",
+                    ty: Ty::Address,
+                },
+                TARGET,
+                LOCKED,
+                PER_BLOCK,
+                STARTING_BLOCK,
+            ],
+        },
+        Call {
+            index: 4,
+            name: "merge_schedules",
+            args: &[
+                Arg {
+                    name: "Schedule 1",
+                    ty: Ty::U32,
+                },
+                Arg {
+                    name: "Schedule 2",
+                    ty: Ty::U32,
+                },
+            ],
+        },
+    ],
+};
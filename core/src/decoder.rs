@@ -0,0 +1,1874 @@
+use crate::{
+    calls::{self, Arg, Ty},
+    error::ErrorCode,
+    fields::{Fields, Value},
+    hash::Blake2b256,
+    networks,
+    sails::{self, Hint},
+    scale::{Error, Reader},
+    ss58::Address,
+};
+use blake2::Digest;
+use core::str;
+
+/// Maximum number of received bytes that are not decoded yet.
+const WINDOW_LEN: usize = 128;
+/// Maximum nesting depth of the decoded values. It limits the nesting of
+/// calls as well, e.g. a batch within a batch takes 5 levels. Together with
+/// the window it bounds the decoder memory, which counts towards the
+/// budget of [`Signer`](crate::signer::Signer).
+const MAX_DEPTH: usize = 8;
+/// Maximum length of `Vec<u8>` shown in hex format. Longer vectors are
+/// shown as their length and Blake2b-256 hash.
+const MAX_HEX_LEN: u32 = 32;
+/// Maximum length of `Vec<u8>` shown as text. Longer text is shown as its
+/// length and Blake2b-256 hash.
+const MAX_TEXT_LEN: u32 = 512;
+/// Number of decimals of VARA.
+const DECIMALS: u32 = 12;
+/// Ticker of VARA.
+const TICKER: &str = "VARA";
+/// Vote convictions by their index.
+const CONVICTIONS: [&str; 7] = [
+    "None (0.1x)",
+    "Locked 1x",
+    "Locked 2x",
+    "Locked 3x",
+    "Locked 4x",
+    "Locked 5x",
+    "Locked 6x",
+];
+
+/// Decoder of the Substrate signing payload.
+///
+/// The payload is fed in chunks as they are received. Call arguments are
+/// decoded on the fly into the fields to be reviewed by the user, and the
+/// signed extensions at the end of the payload are decoded on finish.
+pub struct Decoder {
+    /// Received bytes that are not decoded yet.
+    window: [u8; WINDOW_LEN],
+    window_len: usize,
+    /// Decoding state.
+    parser: Parser,
+}
+
+/// Decoded signing payload.
+#[derive(Default)]
+pub struct Payload {
+    /// Transaction era.
+    pub era: Era,
+    /// Account nonce.
+    pub nonce: u32,
+    /// Tip for the block author.
+    pub tip: u128,
+    /// Runtime spec version.
+    pub spec_version: u32,
+    /// Transaction version.
+    pub transaction_version: u32,
+    /// Genesis block hash.
+    pub genesis_hash: [u8; 32],
+    /// Hash of the block the era starts from.
+    pub block_hash: [u8; 32],
+}
+
+/// Transaction era.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Era {
+    #[default]
+    Immortal,
+    Mortal {
+        period: u64,
+        phase: u64,
+    },
+}
+
+#[derive(Default)]
+struct Parser {
+    phase: Phase,
+    stack: [Frame; MAX_DEPTH],
+    depth: usize,
+    /// Hasher of the long `Vec<u8>` being decoded.
+    hasher: Blake2b256,
+    /// Hint for decoding the message payload.
+    hint: Option<Hint>,
+    /// Whether the hint is applied to the message payload. It applies to
+    /// the first payload only.
+    hint_used: bool,
+    /// Depth of the arguments of the call being hashed.
+    call_depth: Option<usize>,
+    /// Hasher of the call being decoded.
+    call_hasher: Blake2b256,
+    payload: Payload,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Phase {
+    /// Waiting for the call index.
+    #[default]
+    Call,
+    /// Decoding call arguments.
+    Args,
+    /// Waiting for the signed extensions.
+    Extra,
+    /// Payload is decoded.
+    Done,
+    /// Payload can't be decoded.
+    Failed(Error),
+}
+
+#[derive(Clone, Copy)]
+enum Frame {
+    /// Arguments and the index of the next one.
+    Args { args: &'static [Arg], next: usize },
+    /// Bytes left in `Vec<u8>` and whether they are hashed.
+    Bytes {
+        label: Label,
+        remaining: u32,
+        hashed: bool,
+    },
+    /// Bytes left in the UTF-8 text.
+    Text { remaining: u32 },
+    /// Tuple fields and the index of the next one.
+    Tuple {
+        label: Label,
+        args: &'static [Arg],
+        next: usize,
+    },
+    /// Bytes left in the Sails message payload and the hint position of
+    /// the next argument.
+    Sails { remaining: u32, next: usize },
+    /// Items of `Vec<T>` and the index of the next one.
+    Vec {
+        name: &'static str,
+        ty: &'static Ty,
+        next: u32,
+        len: u32,
+    },
+}
+
+/// Label of the decoded value.
+#[derive(Clone, Copy)]
+enum Label {
+    /// Argument name.
+    Name(&'static str),
+    /// Item of the vector, e.g. `Target 1 of 2`.
+    Item {
+        name: &'static str,
+        index: u32,
+        len: u32,
+    },
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Frame::Args { args: &[], next: 0 }
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self {
+            window: [0; WINDOW_LEN],
+            window_len: 0,
+            parser: Default::default(),
+        }
+    }
+}
+
+impl From<ErrorCode> for Error {
+    fn from(_value: ErrorCode) -> Self {
+        // Fields fail only when there is no room for the text
+        Error::Overflow
+    }
+}
+
+impl Decoder {
+    pub fn clear(&mut self) {
+        self.window.fill(0);
+        self.window_len = 0;
+        self.parser.clear();
+    }
+
+    /// Set the hint for decoding the message payload. It must be set
+    /// before the payload is fed.
+    pub fn set_hint(&mut self, data: &[u8]) -> Result<(), ErrorCode> {
+        self.parser.hint = Some(Hint::parse(data)?);
+        Ok(())
+    }
+
+    /// Decode the next chunk of the payload.
+    ///
+    /// Once decoding fails, the following chunks are ignored and the error
+    /// is returned by [`Decoder::finish`].
+    pub fn feed(&mut self, data: &[u8], fields: &mut Fields) {
+        if let Err(err) = self.try_feed(data, fields) {
+            self.parser.phase = Phase::Failed(err);
+        }
+    }
+
+    /// Decode the signed extensions at the end of the payload.
+    pub fn finish(&mut self, fields: &mut Fields) -> Result<&Payload, Error> {
+        match self.parser.phase {
+            Phase::Extra => (),
+            Phase::Done => return Ok(&self.parser.payload),
+            Phase::Failed(err) => return Err(err),
+            _ => return Err(Error::Invalid),
+        }
+        let result = self.parser.extra(&self.window[..self.window_len], fields);
+        self.parser.phase = match result {
+            Ok(()) => Phase::Done,
+            Err(Error::Incomplete) => Phase::Failed(Error::Invalid),
+            Err(err) => Phase::Failed(err),
+        };
+        self.finish(fields)
+    }
+
+    fn try_feed(&mut self, mut data: &[u8], fields: &mut Fields) -> Result<(), Error> {
+        if let Phase::Failed(err) = self.parser.phase {
+            return Err(err);
+        }
+        while !data.is_empty() {
+            let len = data.len().min(WINDOW_LEN - self.window_len);
+            if len == 0 {
+                return Err(Error::Overflow);
+            }
+            self.window[self.window_len..self.window_len + len].copy_from_slice(&data[..len]);
+            self.window_len += len;
+            data = &data[len..];
+
+            let mut pos = 0;
+            let result = loop {
+                match self.parser.step(&self.window[pos..self.window_len], fields) {
+                    Ok(len) => pos += len,
+                    Err(Error::Incomplete) => break Ok(()),
+                    Err(err) => break Err(err),
+                }
+            };
+            self.window.copy_within(pos..self.window_len, 0);
+            self.window_len -= pos;
+            result?;
+        }
+        Ok(())
+    }
+}
+
+impl Parser {
+    /// Reset the state in place, so the parser isn't built on the stack.
+    fn clear(&mut self) {
+        self.phase = Phase::Call;
+        self.depth = 0;
+        Digest::reset(&mut self.hasher);
+        self.hint = None;
+        self.hint_used = false;
+        self.call_depth = None;
+        Digest::reset(&mut self.call_hasher);
+        self.payload = Default::default();
+    }
+
+    /// Decode the next value and return the number of bytes read.
+    fn step(&mut self, data: &[u8], fields: &mut Fields) -> Result<usize, Error> {
+        let len = self.decode_next(data, fields)?;
+        if let Some(depth) = self.call_depth {
+            self.call_hasher.update(&data[..len]);
+            if self.depth < depth {
+                let hash = self.call_hasher.finalize_reset();
+                fields.push("Call hash")?.str("0x")?.hex(&hash)?;
+                self.call_depth = None;
+            }
+        }
+        Ok(len)
+    }
+
+    fn decode_next(&mut self, data: &[u8], fields: &mut Fields) -> Result<usize, Error> {
+        let mut r = Reader::new(data);
+        match self.phase {
+            Phase::Call => {
+                self.value(&mut r, Label::Name("Call"), Ty::Call, fields)?;
+                self.phase = Phase::Args;
+            }
+            Phase::Args => {
+                let top = self.depth - 1;
+                match self.stack[top] {
+                    Frame::Args { args, next } if next == args.len() => self.pop(),
+                    Frame::Args { args, next } => {
+                        let arg = &args[next];
+                        self.value(&mut r, Label::Name(arg.name), arg.ty, fields)?;
+                        self.stack[top] = Frame::Args {
+                            args,
+                            next: next + 1,
+                        };
+                    }
+                    Frame::Text { remaining } => {
+                        let len = r.remaining().min(remaining as usize);
+                        // Character split between the chunks is decoded
+                        // with the next one
+                        let valid = match str::from_utf8(&data[..len]) {
+                            Ok(text) => text.len(),
+                            Err(err) if err.error_len().is_none() && len < remaining as usize => {
+                                err.valid_up_to()
+                            }
+                            Err(_) => return Err(Error::Invalid),
+                        };
+                        if valid == 0 {
+                            return Err(Error::Incomplete);
+                        }
+                        let text = str::from_utf8(r.bytes(valid)?).map_err(|_| Error::Invalid)?;
+                        fields.last_value().text(text)?;
+                        self.stack[top] = Frame::Text {
+                            remaining: remaining - valid as u32,
+                        };
+                        if remaining as usize == valid {
+                            self.pop();
+                        }
+                    }
+                    Frame::Tuple { args, next, .. } if next == args.len() => self.pop(),
+                    Frame::Tuple { label, args, next } => {
+                        let arg = &args[next];
+                        let arg_label = if next == 0 {
+                            label
+                        } else {
+                            Label::Name(arg.name)
+                        };
+                        self.value(&mut r, arg_label, arg.ty, fields)?;
+                        self.stack[top] = Frame::Tuple {
+                            label,
+                            args,
+                            next: next + 1,
+                        };
+                    }
+                    Frame::Sails { remaining, next } => {
+                        let hint = self.hint.as_ref().ok_or(Error::Invalid)?;
+                        let Some((name, ty, following)) = hint.arg(next)? else {
+                            if remaining != 0 {
+                                return Err(Error::Invalid);
+                            }
+                            fields.last_value().str(")")?;
+                            fields.push("Hint hash")?.str("0x")?.hex(hint.hash())?;
+                            self.pop();
+                            return Ok(0);
+                        };
+                        // Arguments must not exceed the payload
+                        let limit = r.remaining().min(remaining as usize);
+                        let mut args = Reader::new(&data[..limit]);
+                        let first = next == hint.first_arg();
+                        match sails_arg(&mut args, name, ty, first, fields) {
+                            Err(Error::Incomplete) if limit == remaining as usize => {
+                                return Err(Error::Invalid)
+                            }
+                            result => result?,
+                        }
+                        r.bytes(args.position())?;
+                        self.stack[top] = Frame::Sails {
+                            remaining: remaining - args.position() as u32,
+                            next: following,
+                        };
+                    }
+                    Frame::Vec { next, len, .. } if next == len => self.pop(),
+                    Frame::Vec {
+                        name,
+                        ty,
+                        next,
+                        len,
+                    } => {
+                        let label = Label::Item {
+                            name,
+                            index: next,
+                            len,
+                        };
+                        self.value(&mut r, label, *ty, fields)?;
+                        self.stack[top] = Frame::Vec {
+                            name,
+                            ty,
+                            next: next + 1,
+                            len,
+                        };
+                    }
+                    Frame::Bytes {
+                        label,
+                        remaining,
+                        hashed,
+                    } => {
+                        let len = r.remaining().min(remaining as usize);
+                        if len == 0 {
+                            return Err(Error::Incomplete);
+                        }
+                        let bytes = r.bytes(len)?;
+                        if hashed {
+                            self.hasher.update(bytes);
+                        } else {
+                            fields.last_value().hex(bytes)?;
+                        }
+                        self.stack[top] = Frame::Bytes {
+                            label,
+                            remaining: remaining - len as u32,
+                            hashed,
+                        };
+                        if remaining as usize == len {
+                            if hashed {
+                                let hash = self.hasher.finalize_reset();
+                                let mut value = fields.begin()?;
+                                write_label(&mut value, label)?;
+                                value.str(" hash")?.end_label().str("0x")?.hex(&hash)?;
+                            }
+                            self.pop();
+                        }
+                    }
+                }
+            }
+            _ => return Err(Error::Incomplete),
+        }
+        Ok(r.position())
+    }
+
+    /// Decode the value of the given type.
+    ///
+    /// All the reads must be done before any field is added or any frame
+    /// is pushed, so the value is either decoded completely or not at all.
+    fn value(
+        &mut self,
+        r: &mut Reader,
+        label: Label,
+        ty: Ty,
+        fields: &mut Fields,
+    ) -> Result<(), Error> {
+        match ty {
+            Ty::Bytes | Ty::Hashed => {
+                let len = r.length()?;
+                let hashed = matches!(ty, Ty::Hashed) || len > MAX_HEX_LEN;
+                if hashed {
+                    field(fields, label)?.number(len as u128)?.str(" bytes")?;
+                } else {
+                    field(fields, label)?.str("0x")?;
+                }
+                if len > 0 {
+                    self.push(Frame::Bytes {
+                        label,
+                        remaining: len,
+                        hashed,
+                    })?;
+                }
+            }
+            Ty::Text => {
+                let len = r.length()?;
+                if len > MAX_TEXT_LEN {
+                    field(fields, label)?.number(len as u128)?.str(" bytes")?;
+                    self.push(Frame::Bytes {
+                        label,
+                        remaining: len,
+                        hashed: true,
+                    })?;
+                } else {
+                    field(fields, label)?;
+                    if len > 0 {
+                        self.push(Frame::Text { remaining: len })?;
+                    }
+                }
+            }
+            Ty::Payload => {
+                let matches = match &self.hint {
+                    Some(hint) if !self.hint_used => matches_hint(&mut r.clone(), hint)?,
+                    _ => return self.value(r, label, Ty::Bytes, fields),
+                };
+                self.hint_used = true;
+                if !matches {
+                    // Payload is decoded as bytes after the mismatch is shown
+                    field(fields, Label::Name("Sails hint"))?.str("Not matched")?;
+                    return self.value(r, label, Ty::Bytes, fields);
+                }
+                let hint = self.hint.as_ref().ok_or(Error::Invalid)?;
+                let len = r.length()?;
+                let start = r.position();
+                for _ in 0..2 {
+                    let name_len = r.length()?;
+                    r.bytes(name_len as usize)?;
+                }
+                let consumed = (r.position() - start) as u32;
+                let next = hint.first_arg();
+                field(fields, label)?
+                    .str(hint.service())?
+                    .str("/")?
+                    .str(hint.method())?
+                    .str("(")?;
+                self.push(Frame::Sails {
+                    remaining: len - consumed,
+                    next,
+                })?;
+            }
+            Ty::Bool => {
+                let value = if r.bool()? { "Yes" } else { "No" };
+                field(fields, label)?.str(value)?;
+            }
+            Ty::U16 => {
+                let value = r.u16()?;
+                field(fields, label)?.number(value as u128)?;
+            }
+            Ty::U32 => {
+                let value = r.u32()?;
+                field(fields, label)?.number(value as u128)?;
+            }
+            Ty::Blocks => {
+                let value = r.u32()?;
+                field(fields, label)?
+                    .number(value as u128)?
+                    .str(" blocks")?;
+            }
+            Ty::U64 => {
+                let value = r.u64()?;
+                field(fields, label)?.number(value as u128)?;
+            }
+            Ty::Compact => {
+                let value = r.compact()?;
+                field(fields, label)?.number(value)?;
+            }
+            Ty::Balance => {
+                let amount = r.compact()?;
+                balance(&mut field(fields, label)?, amount)?;
+            }
+            Ty::FixedBalance => {
+                let amount = r.u128()?;
+                balance(&mut field(fields, label)?, amount)?;
+            }
+            Ty::AccountId => {
+                let key = r.array()?;
+                field(fields, label)?.str(Address::new(&key).as_str())?;
+            }
+            Ty::H256 => {
+                let bytes: [u8; 32] = r.array()?;
+                field(fields, label)?.str("0x")?.hex(&bytes)?;
+            }
+            Ty::H160 => {
+                let bytes: [u8; 20] = r.array()?;
+                field(fields, label)?.str("0x")?.hex(&bytes)?;
+            }
+            Ty::Data => match r.u8()? {
+                0 => {
+                    field(fields, label)?.str("None")?;
+                }
+                tag @ 1..=33 => {
+                    let bytes = r.bytes(tag as usize - 1)?;
+                    match str::from_utf8(bytes) {
+                        Ok(text) => field(fields, label)?.text(text)?,
+                        Err(_) => field(fields, label)?.str("0x")?.hex(bytes)?,
+                    };
+                }
+                tag @ 34..=37 => {
+                    let hash: [u8; 32] = r.array()?;
+                    let name =
+                        ["Blake2-256", "SHA-256", "Keccak-256", "SHA3-256"][tag as usize - 34];
+                    field(fields, label)?.str(name)?.str(" 0x")?.hex(&hash)?;
+                }
+                _ => return Err(Error::Invalid),
+            },
+            Ty::Address => match r.u8()? {
+                0 => {
+                    let key = r.array()?;
+                    field(fields, label)?.str(Address::new(&key).as_str())?;
+                }
+                1 => {
+                    let index = r.compact()?;
+                    field(fields, label)?.str("Index ")?.number(index)?;
+                }
+                2 => {
+                    let len = r.length()?;
+                    if len > MAX_HEX_LEN {
+                        return Err(Error::Invalid);
+                    }
+                    let bytes = r.bytes(len as usize)?;
+                    field(fields, label)?.str("0x")?.hex(bytes)?;
+                }
+                3 => {
+                    let bytes: [u8; 32] = r.array()?;
+                    field(fields, label)?.str("0x")?.hex(&bytes)?;
+                }
+                4 => {
+                    let bytes: [u8; 20] = r.array()?;
+                    field(fields, label)?.str("0x")?.hex(&bytes)?;
+                }
+                _ => return Err(Error::Invalid),
+            },
+            Ty::Call | Ty::HashedCall => {
+                let pallet_index = r.u8()?;
+                let call_index = r.u8()?;
+                let (pallet, call) = calls::find(pallet_index, call_index).ok_or(Error::Unknown)?;
+                let hashed = matches!(ty, Ty::HashedCall);
+                if hashed && self.call_depth.is_some() {
+                    // Only the outermost call is hashed
+                    return Err(Error::Overflow);
+                }
+                field(fields, label)?
+                    .str(pallet.name)?
+                    .str(".")?
+                    .str(call.name)?;
+                self.push(Frame::Args {
+                    args: call.args,
+                    next: 0,
+                })?;
+                if hashed {
+                    self.call_depth = Some(self.depth);
+                }
+            }
+            Ty::Vote => {
+                let vote = r.u8()?;
+                let conviction = CONVICTIONS
+                    .get((vote & 0x7f) as usize)
+                    .ok_or(Error::Invalid)?;
+                let aye = if vote & 0x80 != 0 { "Aye" } else { "Nay" };
+                field(fields, label)?.str(aye)?;
+                fields.push("Conviction")?.str(conviction)?;
+            }
+            Ty::Conviction => {
+                let conviction = CONVICTIONS.get(r.u8()? as usize).ok_or(Error::Invalid)?;
+                field(fields, label)?.str(conviction)?;
+            }
+            Ty::Timepoint => {
+                let height = r.u32()?;
+                let index = r.u32()?;
+                field(fields, label)?
+                    .str("Block ")?
+                    .number(height as u128)?
+                    .str(", index ")?
+                    .number(index as u128)?;
+            }
+            Ty::Weight => {
+                let ref_time = r.compact()?;
+                let proof_size = r.compact()?;
+                field(fields, label)?
+                    .str("Ref time ")?
+                    .number(ref_time)?
+                    .str(", proof size ")?
+                    .number(proof_size)?;
+            }
+            Ty::Option(inner) => match r.u8()? {
+                0 => {
+                    field(fields, label)?.str("None")?;
+                }
+                1 => self.value(r, label, *inner, fields)?,
+                _ => return Err(Error::Invalid),
+            },
+            Ty::Tuple(args) => {
+                self.push(Frame::Tuple {
+                    label,
+                    args,
+                    next: 0,
+                })?;
+            }
+            Ty::Vec(item) => {
+                let len = r.length()?;
+                let name = match label {
+                    Label::Name(name) | Label::Item { name, .. } => name,
+                };
+                if len == 0 {
+                    field(fields, label)?.str("None")?;
+                } else {
+                    self.push(Frame::Vec {
+                        name,
+                        ty: item,
+                        next: 0,
+                        len,
+                    })?;
+                }
+            }
+            Ty::Enum(variants) => {
+                let index = r.u8()?;
+                let variant = variants
+                    .iter()
+                    .find(|variant| variant.index == index)
+                    .ok_or(Error::Invalid)?;
+                field(fields, label)?.str(variant.name)?;
+                if !variant.fields.is_empty() {
+                    self.push(Frame::Args {
+                        args: variant.fields,
+                        next: 0,
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode the signed extensions.
+    fn extra(&mut self, data: &[u8], fields: &mut Fields) -> Result<(), Error> {
+        let mut r = Reader::new(data);
+        let payload = &mut self.payload;
+        payload.era = match r.u8()? {
+            0 => Era::Immortal,
+            first => {
+                let encoded = u16::from_le_bytes([first, r.u8()?]);
+                let period = 2u64 << (encoded % (1 << 4));
+                let quantize_factor = (period >> 12).max(1);
+                let phase = (encoded >> 4) as u64 * quantize_factor;
+                if period < 4 || phase >= period {
+                    return Err(Error::Invalid);
+                }
+                Era::Mortal { period, phase }
+            }
+        };
+        payload.nonce = r.compact()?.try_into().map_err(|_| Error::Invalid)?;
+        payload.tip = r.compact()?;
+        payload.spec_version = r.u32()?;
+        payload.transaction_version = r.u32()?;
+        payload.genesis_hash = r.array()?;
+        payload.block_hash = r.array()?;
+        if r.remaining() != 0 {
+            return Err(Error::Invalid);
+        }
+
+        // Genesis hash is implied by the known network, the tip is shown only
+        // if set and the block hash only if it's unusual, so that calls with
+        // many arguments fit in the fields
+        let network = networks::find(&payload.genesis_hash);
+        fields
+            .push("Network")?
+            .str(network.map_or("Unknown", |n| n.name))?;
+        if network.is_none() {
+            fields
+                .push("Genesis hash")?
+                .str("0x")?
+                .hex(&payload.genesis_hash)?;
+        }
+        match payload.era {
+            Era::Immortal => {
+                fields.push("Era")?.str("Immortal")?;
+            }
+            Era::Mortal { period, phase } => {
+                fields
+                    .push("Era")?
+                    .str("Period ")?
+                    .number(period as u128)?
+                    .str(", phase ")?
+                    .number(phase as u128)?;
+            }
+        }
+        fields.push("Nonce")?.number(payload.nonce as u128)?;
+        if payload.tip != 0 {
+            balance(&mut fields.push("Tip")?, payload.tip)?;
+        }
+        fields
+            .push("Spec / tx version")?
+            .number(payload.spec_version as u128)?
+            .str(" / ")?
+            .number(payload.transaction_version as u128)?;
+        // Immortal transactions are checked against the genesis block
+        if payload.era == Era::Immortal && payload.block_hash != payload.genesis_hash {
+            fields
+                .push("Block hash")?
+                .str("0x")?
+                .hex(&payload.block_hash)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, frame: Frame) -> Result<(), Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(Error::Overflow);
+        }
+        self.stack[self.depth] = frame;
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) {
+        self.depth -= 1;
+        if self.depth == 0 {
+            self.phase = Phase::Extra;
+        }
+    }
+}
+
+/// Add a new field with the given label.
+fn field(fields: &mut Fields, label: Label) -> Result<Value<'_>, Error> {
+    let mut value = fields.begin()?;
+    write_label(&mut value, label)?;
+    value.end_label();
+    Ok(value)
+}
+
+fn write_label(value: &mut Value, label: Label) -> Result<(), Error> {
+    match label {
+        Label::Name(name) => {
+            value.str(name)?;
+        }
+        Label::Item { name, index, len } => {
+            value
+                .str(name)?
+                .str(" ")?
+                .number(index as u128 + 1)?
+                .str(" of ")?
+                .number(len as u128)?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the message payload starts with the service and the method
+/// names of the hint.
+fn matches_hint(r: &mut Reader, hint: &Hint) -> Result<bool, Error> {
+    let len = r.length()? as usize;
+    let start = r.position();
+    for name in [hint.service(), hint.method()] {
+        let name_len = r.length()? as usize;
+        if r.position() - start + name_len > len {
+            return Ok(false);
+        }
+        if r.bytes(name_len)? != name.as_bytes() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Decode the argument of the Sails method and append it to the last
+/// field.
+fn sails_arg(
+    r: &mut Reader,
+    name: &str,
+    ty: sails::Type,
+    first: bool,
+    fields: &mut Fields,
+) -> Result<(), Error> {
+    let mut value = fields.last_value();
+    match ty {
+        sails::Type::Bool => {
+            let arg = if r.bool()? { "true" } else { "false" };
+            sails_name(&mut value, name, first)?.str(arg)?;
+        }
+        sails::Type::U8 => {
+            let arg = r.u8()?;
+            sails_name(&mut value, name, first)?.number(arg as u128)?;
+        }
+        sails::Type::U16 => {
+            let arg = r.u16()?;
+            sails_name(&mut value, name, first)?.number(arg as u128)?;
+        }
+        sails::Type::U32 => {
+            let arg = r.u32()?;
+            sails_name(&mut value, name, first)?.number(arg as u128)?;
+        }
+        sails::Type::U64 => {
+            let arg = r.u64()?;
+            sails_name(&mut value, name, first)?.number(arg as u128)?;
+        }
+        sails::Type::U128 => {
+            let arg = r.u128()?;
+            sails_name(&mut value, name, first)?.number(arg)?;
+        }
+        sails::Type::Str => {
+            let len = r.length()?;
+            let arg = str::from_utf8(r.bytes(len as usize)?).map_err(|_| Error::Invalid)?;
+            sails_name(&mut value, name, first)?
+                .str("\"")?
+                .text(arg)?
+                .str("\"")?;
+        }
+        sails::Type::ActorId | sails::Type::H256 => {
+            let arg: [u8; 32] = r.array()?;
+            sails_name(&mut value, name, first)?.str("0x")?.hex(&arg)?;
+        }
+        sails::Type::Bytes => {
+            let len = r.length()?;
+            let arg = r.bytes(len as usize)?;
+            sails_name(&mut value, name, first)?.str("0x")?.hex(arg)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the name of the Sails method argument.
+fn sails_name<'a, 'b>(
+    value: &'a mut Value<'b>,
+    name: &str,
+    first: bool,
+) -> Result<&'a mut Value<'b>, Error> {
+    if !first {
+        value.str(", ")?;
+    }
+    value.str(name)?.str("=")?;
+    Ok(value)
+}
+
+/// Write the amount of VARA.
+fn balance(value: &mut Value, amount: u128) -> Result<(), Error> {
+    value.decimal(amount, DECIMALS)?.str(" ")?.str(TICKER)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{string::String, vec, vec::Vec};
+
+    /// Immortal era, zero nonce and tip, spec version 1100, transaction
+    /// version 1 and Vara mainnet genesis hash.
+    const EXTRA: &str = "0000004c04000001000000\
+        fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763\
+        fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763";
+
+    const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+    const ALICE_ADDRESS: &str = "kGkLEU3e3XXkJp2WK4eNpVmSab5xUNL9QtmLPh8QfCL2EgotW";
+
+    fn bytes(hex: &str) -> Vec<u8> {
+        let mut bytes = vec![0; hex.len() / 2];
+        hex::decode_to_slice(hex, &mut bytes).unwrap();
+        bytes
+    }
+
+    /// Decode the call with the default signed extensions in chunks of
+    /// different sizes and return the call fields.
+    fn decode(call: &str) -> Result<Vec<(String, String)>, Error> {
+        decode_with_hint(None, call)
+    }
+
+    fn decode_with_hint(hint: Option<&[u8]>, call: &str) -> Result<Vec<(String, String)>, Error> {
+        let mut result = decode_payload(hint, &[call, EXTRA].concat())?;
+        // Signed extensions
+        let extra = result.split_off(result.len() - 4);
+        assert_eq!(
+            extra,
+            fields(&[
+                ("Network", "Vara"),
+                ("Era", "Immortal"),
+                ("Nonce", "0"),
+                ("Spec / tx version", "1100 / 1"),
+            ])
+        );
+        Ok(result)
+    }
+
+    /// Decode the whole payload in chunks of different sizes and return all
+    /// the fields.
+    fn decode_payload(hint: Option<&[u8]>, payload: &str) -> Result<Vec<(String, String)>, Error> {
+        let payload = bytes(payload);
+        let mut result = Vec::new();
+        for chunk_len in [1, 7, payload.len()] {
+            let mut decoder = Decoder::default();
+            if let Some(hint) = hint {
+                decoder.set_hint(hint).unwrap();
+            }
+            let mut fields = Fields::default();
+            for chunk in payload.chunks(chunk_len) {
+                decoder.feed(chunk, &mut fields);
+            }
+            decoder.finish(&mut fields)?;
+            let decoded: Vec<_> = (0..fields.len())
+                .map(|i| (String::from(fields.label(i)), String::from(fields.value(i))))
+                .collect();
+            if chunk_len > 1 {
+                assert_eq!(decoded, result, "chunk length {chunk_len}");
+            }
+            result = decoded;
+        }
+        Ok(result)
+    }
+
+    fn fields(fields: &[(&str, &str)]) -> Vec<(String, String)> {
+        fields
+            .iter()
+            .map(|(label, value)| (String::from(*label), String::from(*value)))
+            .collect()
+    }
+
+    #[test]
+    fn remark() {
+        assert_eq!(
+            decode("00001448656c6c6f").unwrap(),
+            fields(&[("Call", "system.remark"), ("Remark", "0x48656c6c6f")])
+        );
+    }
+
+    #[test]
+    fn unknown_call() {
+        assert_eq!(decode("00ff"), Err(Error::Unknown));
+        assert_eq!(decode("ff00"), Err(Error::Unknown));
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        assert_eq!(decode("00001448656c6c6f00"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn balances_transfer() {
+        assert_eq!(
+            decode(
+                &[
+                    "050300",
+                    ALICE,
+                    // 1.5 VARA
+                    "0b0098f73e5d01"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "balances.transfer_keep_alive"),
+                ("Dest", ALICE_ADDRESS),
+                ("Amount", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode(&["050000", ALICE, "04"].concat()).unwrap(),
+            fields(&[
+                ("Call", "balances.transfer_allow_death"),
+                ("Dest", ALICE_ADDRESS),
+                ("Amount", "0.000000000001 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode("050401a801").unwrap(),
+            fields(&[
+                ("Call", "balances.transfer_all"),
+                ("Dest", "Index 42"),
+                ("Keep alive", "Yes"),
+            ])
+        );
+        // Unknown address type
+        assert_eq!(decode("05040501"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn staking_bond() {
+        assert_eq!(
+            decode("0d000b0098f73e5d0100").unwrap(),
+            fields(&[
+                ("Call", "staking.bond"),
+                ("Amount", "1.5 VARA"),
+                ("Payee", "Staked"),
+            ])
+        );
+        assert_eq!(
+            decode(&["0d0703", ALICE].concat()).unwrap(),
+            fields(&[
+                ("Call", "staking.set_payee"),
+                ("Payee", "Account"),
+                ("Payee account", ALICE_ADDRESS),
+            ])
+        );
+        assert_eq!(
+            decode("0d0200").unwrap(),
+            fields(&[("Call", "staking.unbond"), ("Amount", "0 VARA")])
+        );
+        // Unknown reward destination
+        assert_eq!(decode("0d0705"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn staking_nominate() {
+        assert_eq!(
+            decode(&["0d050800", ALICE, "01a8"].concat()).unwrap(),
+            fields(&[
+                ("Call", "staking.nominate"),
+                ("Target 1 of 2", ALICE_ADDRESS),
+                ("Target 2 of 2", "Index 42"),
+            ])
+        );
+        assert_eq!(
+            decode("0d0500").unwrap(),
+            fields(&[("Call", "staking.nominate"), ("Target", "None")])
+        );
+    }
+
+    #[test]
+    fn staking_payout() {
+        assert_eq!(
+            decode(&["0d12", ALICE, "2a010000"].concat()).unwrap(),
+            fields(&[
+                ("Call", "staking.payout_stakers"),
+                ("Validator", ALICE_ADDRESS),
+                ("Era", "298"),
+            ])
+        );
+        assert_eq!(
+            decode("0d06").unwrap(),
+            fields(&[("Call", "staking.chill")])
+        );
+        assert_eq!(
+            decode("0d0300000000").unwrap(),
+            fields(&[
+                ("Call", "staking.withdraw_unbonded"),
+                ("Slashing spans", "0")
+            ])
+        );
+    }
+
+    #[test]
+    fn nomination_pools() {
+        assert_eq!(
+            decode("1f000b0098f73e5d0107000000").unwrap(),
+            fields(&[
+                ("Call", "nomination_pools.join"),
+                ("Amount", "1.5 VARA"),
+                ("Pool", "7"),
+            ])
+        );
+        assert_eq!(
+            decode("1f01000098f73e5d0100000000000000000000").unwrap(),
+            fields(&[
+                ("Call", "nomination_pools.bond_extra"),
+                ("Extra", "Free balance"),
+                ("Amount", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode("1f0101").unwrap(),
+            fields(&[
+                ("Call", "nomination_pools.bond_extra"),
+                ("Extra", "Rewards")
+            ])
+        );
+        assert_eq!(
+            decode(&["1f0300", ALICE, "0b0098f73e5d01"].concat()).unwrap(),
+            fields(&[
+                ("Call", "nomination_pools.unbond"),
+                ("Member", ALICE_ADDRESS),
+                ("Unbonding points", "1500000000000"),
+            ])
+        );
+        assert_eq!(
+            decode("1f0f03").unwrap(),
+            fields(&[
+                ("Call", "nomination_pools.set_claim_permission"),
+                ("Permission", "Permissionless all"),
+            ])
+        );
+        assert_eq!(
+            decode("1f1407000000").unwrap(),
+            fields(&[("Call", "nomination_pools.claim_commission"), ("Pool", "7")])
+        );
+    }
+
+    #[test]
+    fn gear_send_message() {
+        let destination = "0505050505050505050505050505050505050505050505050505050505050505";
+        assert_eq!(
+            decode(
+                &[
+                    "6803",
+                    destination,
+                    "1448656c6c6f",
+                    // Gas limit
+                    "00e40b5402000000",
+                    // 1.5 VARA
+                    "0098f73e5d0100000000000000000000",
+                    "01"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "gear.send_message"),
+                ("Destination", &["0x", destination].concat()),
+                ("Payload", "0x48656c6c6f"),
+                ("Gas limit", "10000000000"),
+                ("Value", "1.5 VARA"),
+                ("Keep alive", "Yes"),
+            ])
+        );
+        assert_eq!(
+            decode(&["6805", destination].concat()).unwrap(),
+            fields(&[
+                ("Call", "gear.claim_value"),
+                ("Message id", &["0x", destination].concat()),
+            ])
+        );
+    }
+
+    #[test]
+    fn signed_extensions() {
+        let remark = "00001448656c6c6f";
+        let genesis_hash = "11".repeat(32);
+        let block_hash = "22".repeat(32);
+        // Mortal era, tip and unknown network
+        assert_eq!(
+            decode_payload(
+                None,
+                &[
+                    remark,
+                    "5500",
+                    "1c",
+                    "0b0098f73e5d01",
+                    "4c040000",
+                    "01000000",
+                    &genesis_hash,
+                    &block_hash
+                ]
+                .concat()
+            )
+            .unwrap()[2..],
+            fields(&[
+                ("Network", "Unknown"),
+                ("Genesis hash", &["0x", &genesis_hash].concat()),
+                ("Era", "Period 64, phase 5"),
+                ("Nonce", "7"),
+                ("Tip", "1.5 VARA"),
+                ("Spec / tx version", "1100 / 1"),
+            ])
+        );
+        // Immortal era with the block hash other than the genesis hash
+        let extra = &EXTRA[..EXTRA.len() - 64];
+        assert_eq!(
+            decode_payload(None, &[remark, extra, &block_hash].concat()).unwrap()[2..],
+            fields(&[
+                ("Network", "Vara"),
+                ("Era", "Immortal"),
+                ("Nonce", "0"),
+                ("Spec / tx version", "1100 / 1"),
+                ("Block hash", &["0x", &block_hash].concat()),
+            ])
+        );
+    }
+
+    #[test]
+    fn gear_upload_program() {
+        let code = "00".repeat(40);
+        assert_eq!(
+            decode(
+                &[
+                    "6801a0",
+                    &code,
+                    "0000",
+                    "e803000000000000",
+                    "00000000000000000000000000000000",
+                    "00"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "gear.upload_program"),
+                ("Code", "40 bytes"),
+                (
+                    "Code hash",
+                    "0x4a1931803561f431decab002e7425f0a8531d5e456a1a47fd9998a2530c0f800"
+                ),
+                ("Salt", "0x"),
+                ("Payload", "0x"),
+                ("Gas limit", "1000"),
+                ("Value", "0 VARA"),
+                ("Keep alive", "No"),
+            ])
+        );
+    }
+
+    #[test]
+    fn gear_voucher_issue() {
+        let program = "05".repeat(32);
+        assert_eq!(
+            decode(
+                &[
+                    "6b00",
+                    ALICE,
+                    // 1.5 VARA
+                    "0098f73e5d0100000000000000000000",
+                    "0104",
+                    &program,
+                    "01",
+                    "40380000"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "gear_voucher.issue"),
+                ("Spender", ALICE_ADDRESS),
+                ("Balance", "1.5 VARA"),
+                ("Programs", "Listed"),
+                ("Program 1 of 1", &["0x", &program].concat()),
+                ("Code uploading", "Yes"),
+                ("Duration", "14400 blocks"),
+            ])
+        );
+    }
+
+    #[test]
+    fn gear_voucher_call() {
+        let voucher = "07".repeat(32);
+        let program = "05".repeat(32);
+        assert_eq!(
+            decode(
+                &[
+                    "6b01",
+                    &voucher,
+                    "00",
+                    &program,
+                    "00",
+                    "e803000000000000",
+                    "00000000000000000000000000000000",
+                    "00"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "gear_voucher.call"),
+                ("Voucher", &["0x", &voucher].concat()),
+                ("Prepaid call", "gear.send_message"),
+                ("Destination", &["0x", &program].concat()),
+                ("Payload", "0x"),
+                ("Gas limit", "1000"),
+                ("Value", "0 VARA"),
+                ("Keep alive", "No"),
+            ])
+        );
+    }
+
+    #[test]
+    fn gear_voucher_update() {
+        let voucher = "07".repeat(32);
+        assert_eq!(
+            decode(
+                &[
+                    "6b03",
+                    ALICE,
+                    &voucher,
+                    "00",
+                    "00",
+                    "0100",
+                    "0101",
+                    "0164000000"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "gear_voucher.update"),
+                ("Spender", ALICE_ADDRESS),
+                ("Voucher", &["0x", &voucher].concat()),
+                ("New owner", "None"),
+                ("Top up", "None"),
+                ("Add programs", "Any"),
+                ("Code uploading", "Yes"),
+                ("Prolong duration", "100 blocks"),
+            ])
+        );
+        // Invalid option
+        assert_eq!(
+            decode(&["6b03", ALICE, &voucher, "02"].concat()),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn gear_send_message_sails() {
+        const HINT: &[u8] = b"\x03Vft\x08Transfer\x02to\x07\x05value\x05";
+        let destination = "0505050505050505050505050505050505050505050505050505050505050505";
+        let message = |payload: &str| {
+            [
+                "6803",
+                destination,
+                payload,
+                "e803000000000000",
+                "00000000000000000000000000000000",
+                "00",
+            ]
+            .concat()
+        };
+        // `Vft/Transfer(to, 1000)`
+        let payload = [
+            "f4",
+            "0c566674",
+            "205472616e73666572",
+            destination,
+            "e8030000000000000000000000000000",
+        ]
+        .concat();
+        assert_eq!(
+            decode_with_hint(Some(HINT), &message(&payload)).unwrap(),
+            fields(&[
+                ("Call", "gear.send_message"),
+                ("Destination", &["0x", destination].concat()),
+                (
+                    "Payload",
+                    &["Vft/Transfer(to=0x", destination, ", value=1000)"].concat()
+                ),
+                (
+                    "Hint hash",
+                    "0x8bc1ebb3f03d8e8198b5c2c37db8063508e8ee0252ffef0c17470bc70faf8981"
+                ),
+                ("Gas limit", "1000"),
+                ("Value", "0 VARA"),
+                ("Keep alive", "No"),
+            ])
+        );
+        // Method doesn't match the hint
+        let payload_hash = "0xf2fd10084ee417c62f34a4c257614d128737069889f3ad1d6224ffdb93894385";
+        assert_eq!(
+            decode_with_hint(
+                Some(b"\x03Vft\x07Approve\x02to\x07\x05value\x05"),
+                &message(&payload)
+            )
+            .unwrap()[2..5],
+            fields(&[
+                ("Sails hint", "Not matched"),
+                ("Payload", "61 bytes"),
+                ("Payload hash", payload_hash),
+            ])
+        );
+        // Hint applies to the first message only
+        let batch = decode_with_hint(
+            Some(HINT),
+            &["080008", &message(&payload), &message(&payload)].concat(),
+        )
+        .unwrap();
+        assert_eq!(batch[3].0, "Payload");
+        assert!(batch[3].1.starts_with("Vft/Transfer("));
+        assert_eq!(
+            batch[9..12],
+            fields(&[
+                ("Destination", &["0x", destination].concat()),
+                ("Payload", "61 bytes"),
+                ("Payload hash", payload_hash),
+            ])
+        );
+        // Payload is shorter than the arguments
+        let short = ["f0", &payload[2..payload.len() - 2]].concat();
+        assert_eq!(
+            decode_with_hint(Some(HINT), &message(&short)),
+            Err(Error::Invalid)
+        );
+        // Payload is longer than the arguments
+        let long = ["f8", &payload[2..], "00"].concat();
+        assert_eq!(
+            decode_with_hint(Some(HINT), &message(&long)),
+            Err(Error::Invalid)
+        );
+        // `Vft/SetName("Al\u{e9}")`, the non-ASCII character is escaped
+        let payload = ["44", "0c566674", "1c5365744e616d65", "10416cc3a9"].concat();
+        assert_eq!(
+            decode_with_hint(Some(b"\x03Vft\x07SetName\x04name\x06"), &message(&payload)).unwrap()
+                [2],
+            ("Payload".into(), "Vft/SetName(name=\"Al\\u{e9}\")".into())
+        );
+    }
+
+    #[test]
+    fn utility_batch() {
+        let transfer = ["050300", ALICE, "0b0098f73e5d01"].concat();
+        let remark = "00001448656c6c6f";
+        assert_eq!(
+            decode(&["080208", &transfer, remark].concat()).unwrap(),
+            fields(&[
+                ("Call", "utility.batch_all"),
+                ("Call 1 of 2", "balances.transfer_keep_alive"),
+                ("Dest", ALICE_ADDRESS),
+                ("Amount", "1.5 VARA"),
+                ("Call 2 of 2", "system.remark"),
+                ("Remark", "0x48656c6c6f"),
+            ])
+        );
+        assert_eq!(
+            decode(&["0801", "0500", "080004", remark].concat()).unwrap(),
+            fields(&[
+                ("Call", "utility.as_derivative"),
+                ("Derivative index", "5"),
+                ("Call", "utility.batch"),
+                ("Call 1 of 1", "system.remark"),
+                ("Remark", "0x48656c6c6f"),
+            ])
+        );
+        assert_eq!(
+            decode("080400").unwrap(),
+            fields(&[("Call", "utility.force_batch"), ("Call", "None")])
+        );
+        // Unknown inner call
+        assert_eq!(decode("08000400ff"), Err(Error::Unknown));
+    }
+
+    #[test]
+    fn utility_batch_depth() {
+        let remark = "00001448656c6c6f";
+        let nested = |depth| ["080004".repeat(depth), remark.into()].concat();
+        assert_eq!(decode(&nested(3)).unwrap().len(), 5);
+        assert_eq!(decode(&nested(4)), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn proxy() {
+        let transfer = ["050300", ALICE, "0b0098f73e5d01"].concat();
+        assert_eq!(
+            decode(&["190000", ALICE, "00", &transfer].concat()).unwrap(),
+            fields(&[
+                ("Call", "proxy.proxy"),
+                ("Real", ALICE_ADDRESS),
+                ("Force type", "None"),
+                ("Call", "balances.transfer_keep_alive"),
+                ("Dest", ALICE_ADDRESS),
+                ("Amount", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode(&["190100", ALICE, "0300000000"].concat()).unwrap(),
+            fields(&[
+                ("Call", "proxy.add_proxy"),
+                ("Delegate", ALICE_ADDRESS),
+                ("Proxy type", "Staking"),
+                ("Delay", "0 blocks"),
+            ])
+        );
+        assert_eq!(
+            decode(&["190500", ALICE, "000000", "a10f", "08"].concat()).unwrap(),
+            fields(&[
+                ("Call", "proxy.kill_pure"),
+                ("Spawner", ALICE_ADDRESS),
+                ("Proxy type", "Any"),
+                ("Index", "0"),
+                ("Height", "1000"),
+                ("Extrinsic index", "2"),
+            ])
+        );
+        // Unknown proxy type
+        assert_eq!(decode("1904060000000000"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn multisig_as_multi() {
+        let transfer = ["050300", ALICE, "0b0098f73e5d01"].concat();
+        assert_eq!(
+            decode(
+                &[
+                    "1a01", "0200", "04", ALICE, "00", &transfer, // Weight
+                    "a10f04"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "multisig.as_multi"),
+                ("Threshold", "2"),
+                ("Signatory 1 of 1", ALICE_ADDRESS),
+                ("Timepoint", "None"),
+                ("Call", "balances.transfer_keep_alive"),
+                ("Dest", ALICE_ADDRESS),
+                ("Amount", "1.5 VARA"),
+                (
+                    "Call hash",
+                    "0x95a94f141c3b2fab9cb32bee238a88101bb4cf34d8cd1cea1fca3b3416e75e57"
+                ),
+                ("Max weight", "Ref time 1000, proof size 1"),
+            ])
+        );
+        // Nested hashed call
+        let nested = ["1a01020000000d06", "0000"].concat();
+        let call = ["1a0102000000", &nested, "0000"].concat();
+        assert_eq!(decode(&call), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn multisig_approve() {
+        let hash = "07".repeat(32);
+        assert_eq!(
+            decode(
+                &[
+                    "1a02",
+                    "0300",
+                    "08",
+                    ALICE,
+                    ALICE,
+                    "016400000002000000",
+                    &hash,
+                    "0000"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "multisig.approve_as_multi"),
+                ("Threshold", "3"),
+                ("Signatory 1 of 2", ALICE_ADDRESS),
+                ("Signatory 2 of 2", ALICE_ADDRESS),
+                ("Timepoint", "Block 100, index 2"),
+                ("Call hash", &["0x", &hash].concat()),
+                ("Max weight", "Ref time 0, proof size 0"),
+            ])
+        );
+        assert_eq!(
+            decode(&["1a03", "0200", "00", "6400000002000000", &hash].concat()).unwrap(),
+            fields(&[
+                ("Call", "multisig.cancel_as_multi"),
+                ("Threshold", "2"),
+                ("Signatory", "None"),
+                ("Timepoint", "Block 100, index 2"),
+                ("Call hash", &["0x", &hash].concat()),
+            ])
+        );
+    }
+
+    #[test]
+    fn conviction_voting() {
+        // 1.5 VARA
+        let balance = "0098f73e5d0100000000000000000000";
+        assert_eq!(
+            decode(&["1000", "a8", "00", "83", balance].concat()).unwrap(),
+            fields(&[
+                ("Call", "conviction_voting.vote"),
+                ("Referendum", "42"),
+                ("Vote type", "Standard"),
+                ("Vote", "Aye"),
+                ("Conviction", "Locked 3x"),
+                ("Balance", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode(&["1000", "a8", "01", balance, balance].concat()).unwrap(),
+            fields(&[
+                ("Call", "conviction_voting.vote"),
+                ("Referendum", "42"),
+                ("Vote type", "Split"),
+                ("Aye", "1.5 VARA"),
+                ("Nay", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode(&["1001", "0200", "00", ALICE, "00", balance].concat()).unwrap(),
+            fields(&[
+                ("Call", "conviction_voting.delegate"),
+                ("Track", "2"),
+                ("To", ALICE_ADDRESS),
+                ("Conviction", "None (0.1x)"),
+                ("Balance", "1.5 VARA"),
+            ])
+        );
+        assert_eq!(
+            decode("1004002a000000").unwrap(),
+            fields(&[
+                ("Call", "conviction_voting.remove_vote"),
+                ("Track", "None"),
+                ("Referendum", "42"),
+            ])
+        );
+        // Unknown conviction
+        assert_eq!(
+            decode(&["1000", "a8", "00", "07", balance].concat()),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn referenda() {
+        let hash = "07".repeat(32);
+        assert_eq!(
+            decode(&["1100", "1400", "02", &hash, "0a000000", "01", "64000000"].concat()).unwrap(),
+            fields(&[
+                ("Call", "referenda.submit"),
+                ("Origin", "Origins"),
+                ("Track origin", "Staking admin"),
+                ("Proposal", "Lookup"),
+                ("Hash", &["0x", &hash].concat()),
+                ("Length", "10"),
+                ("Enactment", "After"),
+                ("Delay", "100 blocks"),
+            ])
+        );
+        assert_eq!(
+            decode("11012a000000").unwrap(),
+            fields(&[
+                ("Call", "referenda.place_decision_deposit"),
+                ("Referendum", "42")
+            ])
+        );
+    }
+
+    #[test]
+    fn session_keys() {
+        assert_eq!(
+            decode(&["0700", &ALICE.repeat(4), "00"].concat()).unwrap(),
+            fields(&[
+                ("Call", "session.set_keys"),
+                ("Babe key", ALICE_ADDRESS),
+                ("Grandpa key", ALICE_ADDRESS),
+                ("ImOnline key", ALICE_ADDRESS),
+                ("Authority discovery key", ALICE_ADDRESS),
+                ("Proof", "0 bytes"),
+            ])
+        );
+        assert_eq!(
+            decode("0701").unwrap(),
+            fields(&[("Call", "session.purge_keys")])
+        );
+    }
+
+    #[test]
+    fn identity_set_identity() {
+        let hash = "07".repeat(32);
+        assert_eq!(
+            decode(
+                &[
+                    "1801",
+                    // Additional fields
+                    "0408446973636f726408616c6963652331",
+                    // Display
+                    "06416c696365",
+                    // Legal
+                    "00",
+                    // Web
+                    "1368747470733a2f2f616c6963652e76617261",
+                    // Riot
+                    "00",
+                    // Email
+                    "13616c69636540766172612e6e6574776f726b",
+                    // PGP fingerprint
+                    "00",
+                    // Image
+                    "22",
+                    &hash,
+                    // Twitter
+                    "03fffe"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "identity.set_identity"),
+                ("Additional 1 of 1", "Discord"),
+                ("Value", "alice#1"),
+                ("Display", "Alice"),
+                ("Legal", "None"),
+                ("Web", "https://alice.vara"),
+                ("Riot", "None"),
+                ("Email", "alice@vara.network"),
+                ("PGP fingerprint", "None"),
+                ("Image", &["Blake2-256 0x", &hash].concat()),
+                ("Twitter", "0xfffe"),
+            ])
+        );
+    }
+
+    #[test]
+    fn identity_subs() {
+        assert_eq!(
+            decode(&["1802", "08", ALICE, "065374617368", ALICE, "00"].concat()).unwrap(),
+            fields(&[
+                ("Call", "identity.set_subs"),
+                ("Sub 1 of 2", ALICE_ADDRESS),
+                ("Name", "Stash"),
+                ("Sub 2 of 2", ALICE_ADDRESS),
+                ("Name", "None"),
+            ])
+        );
+        assert_eq!(
+            decode(&["180b00", ALICE, "0b436f6e74726f6c6c6572"].concat()).unwrap(),
+            fields(&[
+                ("Call", "identity.add_sub"),
+                ("Sub", ALICE_ADDRESS),
+                ("Name", "Controller"),
+            ])
+        );
+        // Look-alike, zero-width and control characters are escaped
+        assert_eq!(
+            decode(&["180b00", ALICE, "0cd0906c696365e2808b075c"].concat()).unwrap(),
+            fields(&[
+                ("Call", "identity.add_sub"),
+                ("Sub", ALICE_ADDRESS),
+                ("Name", "\\u{410}lice\\u{200b}\\u{7}\\\\"),
+            ])
+        );
+        // Unknown data type
+        assert_eq!(
+            decode(&["180b00", ALICE, "26"].concat()),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn vesting() {
+        assert_eq!(
+            decode(
+                &[
+                    "0a0200",
+                    ALICE,
+                    // 1000 VARA
+                    "0080c6a47e8d03000000000000000000",
+                    // 0.01 VARA
+                    "00e40b54020000000000000000000000",
+                    "a0860100"
+                ]
+                .concat()
+            )
+            .unwrap(),
+            fields(&[
+                ("Call", "vesting.vested_transfer"),
+                ("Target", ALICE_ADDRESS),
+                ("Locked", "1000 VARA"),
+                ("Per block", "0.01 VARA"),
+                ("Starting block", "100000"),
+            ])
+        );
+        assert_eq!(
+            decode("0a040000000001000000").unwrap(),
+            fields(&[
+                ("Call", "vesting.merge_schedules"),
+                ("Schedule 1", "0"),
+                ("Schedule 2", "1"),
+            ])
+        );
+        assert_eq!(decode("0a00").unwrap(), fields(&[("Call", "vesting.vest")]));
+    }
+
+    #[test]
+    fn treasury() {
+        assert_eq!(
+            decode(&["0e05", "0b0098f73e5d01", ALICE, "01e8030000"].concat()).unwrap(),
+            fields(&[
+                ("Call", "treasury.spend"),
+                ("Amount", "1.5 VARA"),
+                ("Beneficiary", ALICE_ADDRESS),
+                ("Valid from", "1000"),
+            ])
+        );
+        assert_eq!(
+            decode("0e0602000000").unwrap(),
+            fields(&[("Call", "treasury.payout"), ("Spend", "2")])
+        );
+    }
+
+    #[test]
+    fn bounties() {
+        // Multibyte characters are split between the chunks
+        assert_eq!(
+            decode(&["1d00", "0b0098f73e5d01", "c0", "d09fd0b5d180d0b5d0b2d0bed0b420d0b4d0bed0bad183d0bcd0b5d0bdd182d0b0d186d0b8d0b820e2809420646f6373"].concat())
+                .unwrap(),
+            fields(&[
+                ("Call", "bounties.propose_bounty"),
+                ("Value", "1.5 VARA"),
+                (
+                    "Description",
+                    "\\u{41f}\\u{435}\\u{440}\\u{435}\\u{432}\\u{43e}\\u{434} \\u{434}\\u{43e}\\u{43a}\\u{443}\\u{43c}\\u{435}\\u{43d}\\u{442}\\u{430}\\u{446}\\u{438}\\u{438} \\u{2014} docs",
+                ),
+            ])
+        );
+        assert_eq!(
+            decode(&["1d00", "00", "0c610a62"].concat()).unwrap(),
+            fields(&[
+                ("Call", "bounties.propose_bounty"),
+                ("Value", "0 VARA"),
+                ("Description", "a\\u{a}b"),
+            ])
+        );
+        // Long description
+        assert_eq!(
+            decode(&["1d00", "00", "6109", &"61".repeat(600)].concat()).unwrap(),
+            fields(&[
+                ("Call", "bounties.propose_bounty"),
+                ("Value", "0 VARA"),
+                ("Description", "600 bytes"),
+                (
+                    "Description hash",
+                    "0xd9a6276a6d66fac7bbf0bc39a3a6620911d637f6a048c3aa4d0a67930aa1aa8b"
+                ),
+            ])
+        );
+        // Invalid UTF-8
+        assert_eq!(decode("1d00000cfffe"), Err(Error::Invalid));
+        assert_eq!(decode("1d000008d0"), Err(Error::Invalid));
+        assert_eq!(
+            decode(&["1e0404", "08", "00", ALICE].concat()).unwrap(),
+            fields(&[
+                ("Call", "child_bounties.award_child_bounty"),
+                ("Parent bounty", "1"),
+                ("Child bounty", "2"),
+                ("Beneficiary", ALICE_ADDRESS),
+            ])
+        );
+    }
+}
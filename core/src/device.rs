@@ -0,0 +1,20 @@
+use crate::error::ErrorCode;
+use rand_core::{CryptoRng, RngCore};
+
+/// Cryptographic functions provided by the device.
+pub trait Device {
+    /// Random number generator used for signing.
+    type Rng: CryptoRng + RngCore + Clone;
+
+    /// Derive the Ed25519 private key by the hardened derivation path.
+    fn derive_private_key(&self, path: &[u32]) -> Result<[u8; 32], ErrorCode>;
+
+    /// Get the Ed25519 public key of the private key.
+    fn ed25519_public_key(&self, private_key: &[u8; 32]) -> Result<[u8; 32], ErrorCode>;
+
+    /// Sign the message with the Ed25519 private key.
+    fn ed25519_sign(&self, private_key: &[u8; 32], message: &[u8]) -> Result<[u8; 64], ErrorCode>;
+
+    /// Get the random number generator.
+    fn rng(&self) -> Self::Rng;
+}
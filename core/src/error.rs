@@ -0,0 +1,90 @@
+use core::{array::TryFromSliceError, num::ParseIntError};
+use schnorrkel::SignatureError;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u16)]
+pub enum ErrorCode {
+    NothingReceived = 0x6982,
+    Unknown = 0x6d00,
+    Unimplemented = 0x6d01,
+    BadCla = 0x6e00,
+    BadIns = 0x6e01,
+    BadP1P2 = 0x6e02,
+    BadLen = 0x6e03,
+    BadPath = 0x6f00,
+    UserCancelled = 0x6e04,
+    BlindSigningDisabled = 0x6e05,
+    ParsingError = 0x7f00,
+    ConversionError = 0x7f01,
+    CryptoError = 0x7f02,
+    SignatureError = 0x7f03,
+    Ok = 0x9000,
+    Panic = 0xe000,
+}
+
+impl TryFrom<u16> for ErrorCode {
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let code = match value {
+            0x6982 => ErrorCode::NothingReceived,
+            0x6d00 => ErrorCode::Unknown,
+            0x6d01 => ErrorCode::Unimplemented,
+            0x6e00 => ErrorCode::BadCla,
+            0x6e01 => ErrorCode::BadIns,
+            0x6e02 => ErrorCode::BadP1P2,
+            0x6e03 => ErrorCode::BadLen,
+            0x6f00 => ErrorCode::BadPath,
+            0x6e04 => ErrorCode::UserCancelled,
+            0x6e05 => ErrorCode::BlindSigningDisabled,
+            0x7f00 => ErrorCode::ParsingError,
+            0x7f01 => ErrorCode::ConversionError,
+            0x7f02 => ErrorCode::CryptoError,
+            0x7f03 => ErrorCode::SignatureError,
+            0x9000 => ErrorCode::Ok,
+            0xe000 => ErrorCode::Panic,
+            _ => return Err(value),
+        };
+        Ok(code)
+    }
+}
+
+impl From<ParseIntError> for ErrorCode {
+    fn from(_value: ParseIntError) -> Self {
+        ErrorCode::ParsingError
+    }
+}
+
+impl From<TryFromSliceError> for ErrorCode {
+    fn from(_value: TryFromSliceError) -> Self {
+        ErrorCode::ConversionError
+    }
+}
+
+impl From<SignatureError> for ErrorCode {
+    fn from(_value: SignatureError) -> Self {
+        ErrorCode::SignatureError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_word() {
+        for code in [
+            ErrorCode::NothingReceived,
+            ErrorCode::BadP1P2,
+            ErrorCode::BadPath,
+            ErrorCode::UserCancelled,
+            ErrorCode::BlindSigningDisabled,
+            ErrorCode::SignatureError,
+            ErrorCode::Ok,
+            ErrorCode::Panic,
+        ] {
+            assert_eq!(ErrorCode::try_from(code as u16), Ok(code));
+        }
+        assert_eq!(ErrorCode::try_from(0x6a80), Err(0x6a80));
+    }
+}
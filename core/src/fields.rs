@@ -0,0 +1,282 @@
+use crate::error::ErrorCode;
+use core::str;
+
+/// Maximum length of the text stored for all fields on Nano S. It's enough
+/// for the condensed signed extensions and a call with a few hashes.
+pub const NANOS_TEXT_LEN: usize = 640;
+/// Maximum number of fields on Nano S.
+pub const NANOS_MAX_FIELDS: usize = 16;
+
+/// Maximum length of the text stored for all fields.
+#[cfg(target_os = "nanos")]
+const TEXT_LEN: usize = NANOS_TEXT_LEN;
+#[cfg(not(target_os = "nanos"))]
+const TEXT_LEN: usize = 2048;
+/// Maximum number of fields.
+#[cfg(target_os = "nanos")]
+pub(crate) const MAX_FIELDS: usize = NANOS_MAX_FIELDS;
+#[cfg(not(target_os = "nanos"))]
+pub(crate) const MAX_FIELDS: usize = 32;
+
+/// Number of characters in a single line of a field value.
+pub const LINE_LEN: usize = 17;
+/// Number of value lines in a single page. Nano S fits only one line under
+/// the title.
+#[cfg(target_os = "nanos")]
+pub const LINES_PER_PAGE: usize = 1;
+#[cfg(not(target_os = "nanos"))]
+pub const LINES_PER_PAGE: usize = 3;
+
+/// Labeled text fields to be reviewed by the user.
+pub struct Fields {
+    text: [u8; TEXT_LEN],
+    text_len: usize,
+    items: [Item; MAX_FIELDS],
+    len: usize,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Item {
+    label_start: u16,
+    value_start: u16,
+    value_end: u16,
+}
+
+/// Writer of the last field.
+pub struct Value<'a> {
+    fields: &'a mut Fields,
+    /// Whether the label is being written.
+    label: bool,
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Self {
+            text: [0; TEXT_LEN],
+            text_len: 0,
+            items: [Default::default(); MAX_FIELDS],
+            len: 0,
+        }
+    }
+}
+
+impl Fields {
+    pub fn clear(&mut self) {
+        self.text.fill(0);
+        self.text_len = 0;
+        self.len = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add a new field and return the writer of its value.
+    pub fn push(&mut self, label: &str) -> Result<Value<'_>, ErrorCode> {
+        let mut value = self.begin()?;
+        value.str(label)?.end_label();
+        Ok(value)
+    }
+
+    /// Add a new field and return the writer of its label.
+    ///
+    /// The value is written after [`Value::end_label`] is called.
+    pub fn begin(&mut self) -> Result<Value<'_>, ErrorCode> {
+        if self.len == MAX_FIELDS {
+            return Err(ErrorCode::BadLen);
+        }
+        let start = self.text_len as u16;
+        self.items[self.len] = Item {
+            label_start: start,
+            value_start: start,
+            value_end: start,
+        };
+        self.len += 1;
+        Ok(Value {
+            fields: self,
+            label: true,
+        })
+    }
+
+    /// Return the writer of the last field value to append more text.
+    pub fn last_value(&mut self) -> Value<'_> {
+        Value {
+            fields: self,
+            label: false,
+        }
+    }
+
+    pub fn label(&self, index: usize) -> &str {
+        let item = &self.items[index];
+        self.str(item.label_start, item.value_start)
+    }
+
+    pub fn value(&self, index: usize) -> &str {
+        let item = &self.items[index];
+        self.str(item.value_start, item.value_end)
+    }
+
+    /// Number of pages needed to show the field value.
+    pub fn pages(&self, index: usize) -> usize {
+        let mut value = self.value(index);
+        let mut lines: usize = 0;
+        while !value.is_empty() {
+            value = &value[line_end(value)..];
+            lines += 1;
+        }
+        lines.max(1).div_ceil(LINES_PER_PAGE)
+    }
+
+    /// Value lines to be shown on the given page.
+    pub fn page_lines(&self, index: usize, page: usize) -> [&str; LINES_PER_PAGE] {
+        let mut value = self.value(index);
+        for _ in 0..page * LINES_PER_PAGE {
+            value = &value[line_end(value)..];
+        }
+        let mut lines = [""; LINES_PER_PAGE];
+        for line in lines.iter_mut() {
+            let end = line_end(value);
+            *line = &value[..end];
+            value = &value[end..];
+        }
+        lines
+    }
+
+    fn str(&self, start: u16, end: u16) -> &str {
+        str::from_utf8(&self.text[start as usize..end as usize]).unwrap_or_default()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), ErrorCode> {
+        let end = self.text_len + bytes.len();
+        if end > TEXT_LEN {
+            return Err(ErrorCode::BadLen);
+        }
+        self.text[self.text_len..end].copy_from_slice(bytes);
+        self.text_len = end;
+        Ok(())
+    }
+}
+
+impl Value<'_> {
+    /// Finish writing the label and start writing the value.
+    pub fn end_label(&mut self) -> &mut Self {
+        self.label = false;
+        self
+    }
+
+    pub fn str(&mut self, s: &str) -> Result<&mut Self, ErrorCode> {
+        self.write(s.as_bytes())?;
+        Ok(self)
+    }
+
+    /// Write the text with the characters other than printable ASCII
+    /// escaped as `\u{..}`, as the device font shows printable ASCII only.
+    /// Backslash is escaped as well, so the escapes can't be spoofed.
+    pub fn text(&mut self, s: &str) -> Result<&mut Self, ErrorCode> {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if matches!(c, ' '..='~') && c != '\\' {
+                continue;
+            }
+            self.str(&s[start..i])?;
+            if c == '\\' {
+                self.str("\\\\")?;
+            } else {
+                let code = c as u32;
+                let digits = (u32::BITS - code.leading_zeros()).div_ceil(4).max(1);
+                self.str("\\u{")?;
+                for shift in (0..digits).rev() {
+                    let digit = (code >> (shift * 4)) & 0xf;
+                    self.write(&[b"0123456789abcdef"[digit as usize]])?;
+                }
+                self.str("}")?;
+            }
+            start = i + c.len_utf8();
+        }
+        self.str(&s[start..])
+    }
+
+    pub fn number(&mut self, mut n: u128) -> Result<&mut Self, ErrorCode> {
+        let mut digits = [0; 39];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        self.write(&digits[i..])?;
+        Ok(self)
+    }
+
+    /// Write fixed-point number with the given number of decimals without
+    /// trailing zeros, e.g. `1.5`.
+    pub fn decimal(&mut self, n: u128, decimals: u32) -> Result<&mut Self, ErrorCode> {
+        let unit = 10u128.pow(decimals);
+        self.number(n / unit)?;
+        let mut fraction = n % unit;
+        if fraction > 0 {
+            let mut digits = [0; 38];
+            let mut len = decimals as usize;
+            for digit in digits[..len].iter_mut().rev() {
+                *digit = b'0' + (fraction % 10) as u8;
+                fraction /= 10;
+            }
+            while digits[len - 1] == b'0' {
+                len -= 1;
+            }
+            self.str(".")?;
+            self.write(&digits[..len])?;
+        }
+        Ok(self)
+    }
+
+    pub fn hex(&mut self, bytes: &[u8]) -> Result<&mut Self, ErrorCode> {
+        let mut buf = [0; 2];
+        for byte in bytes {
+            _ = hex::encode_to_slice([*byte], &mut buf);
+            self.write(&buf)?;
+        }
+        Ok(self)
+    }
+
+    /// Write derivation path in `44'/913'/0'/0'/0'` format.
+    pub fn path(&mut self, path: &[u32]) -> Result<&mut Self, ErrorCode> {
+        for (i, p) in path.iter().enumerate() {
+            if i > 0 {
+                self.str("/")?;
+            }
+            self.number((p & 0x7fffffff) as u128)?;
+            if p & 0x80000000 != 0 {
+                self.str("'")?;
+            }
+        }
+        Ok(self)
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), ErrorCode> {
+        self.fields.write(bytes)?;
+        let end = self.fields.text_len as u16;
+        let item = &mut self.fields.items[self.fields.len - 1];
+        if self.label {
+            item.value_start = end;
+        }
+        item.value_end = end;
+        Ok(())
+    }
+}
+
+/// End of the first line of the text.
+fn line_end(text: &str) -> usize {
+    let mut end = text.len().min(LINE_LEN);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    end
+}
@@ -0,0 +1,4 @@
+use blake2::{digest::consts::U32, Blake2b};
+
+/// BLAKE2b hasher with 256-bit output used by Substrate.
+pub type Blake2b256 = Blake2b<U32>;
@@ -0,0 +1,18 @@
+//! Vara application logic that doesn't depend on the device SDK.
+
+#![no_std]
+
+pub mod apdu;
+pub mod calls;
+pub mod decoder;
+pub mod device;
+pub mod error;
+pub mod fields;
+pub mod hash;
+pub mod networks;
+pub mod path;
+pub mod sails;
+pub mod scale;
+pub mod signer;
+pub mod ss58;
+pub mod transcript;
@@ -0,0 +1,62 @@
+//! Known Vara networks identified by their genesis hash.
+
+/// Network the transaction is signed for.
+pub struct Network {
+    pub name: &'static str,
+    pub genesis_hash: [u8; 32],
+}
+
+pub static NETWORKS: &[Network] = &[
+    Network {
+        name: "Vara",
+        genesis_hash: hex_literal(
+            b"fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763",
+        ),
+    },
+    Network {
+        name: "Vara Testnet",
+        genesis_hash: hex_literal(
+            b"525639f713f397dcf839bd022cd821f367ebcf179de7b9253531f8adbe5436d6",
+        ),
+    },
+];
+
+/// Find the network by its genesis hash.
+pub fn find(genesis_hash: &[u8; 32]) -> Option<&'static Network> {
+    NETWORKS.iter().find(|n| &n.genesis_hash == genesis_hash)
+}
+
+/// Decode the 32-byte hash in hex format at compile time.
+const fn hex_literal(hex: &[u8; 64]) -> [u8; 32] {
+    const fn digit(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let mut bytes = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        bytes[i] = digit(hex[2 * i]) << 4 | digit(hex[2 * i + 1]);
+        i += 1;
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_network() {
+        let mut genesis_hash = [0; 32];
+        hex::decode_to_slice(
+            "fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763",
+            &mut genesis_hash,
+        )
+        .unwrap();
+        assert_eq!(find(&genesis_hash).map(|n| n.name), Some("Vara"));
+        assert!(find(&[0; 32]).is_none());
+    }
+}
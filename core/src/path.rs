@@ -0,0 +1,69 @@
+use crate::error::ErrorCode;
+
+/// Number of derivation path components.
+pub const PATH_LEN: usize = 5;
+
+/// BIP44 purpose.
+const PURPOSE: u32 = 0x8000002c;
+/// SLIP-44 coin type of Vara.
+const COIN_TYPE: u32 = 0x80000391;
+
+/// Derivation path `44'/913'/account'/change'/index'`.
+pub type Path = [u32; PATH_LEN];
+
+/// Parse the derivation path of little-endian components.
+pub fn parse(data: &[u8]) -> Result<Path, ErrorCode> {
+    if data.len() != PATH_LEN * 4 {
+        return Err(ErrorCode::BadLen);
+    }
+    let mut path = [0; PATH_LEN];
+    for (p, bytes) in path.iter_mut().zip(data.as_chunks().0) {
+        *p = u32::from_le_bytes(*bytes);
+    }
+    Ok(path)
+}
+
+/// Check that the path belongs to Vara.
+pub fn check(path: &Path) -> Result<(), ErrorCode> {
+    if path[0] != PURPOSE || path[1] != COIN_TYPE {
+        return Err(ErrorCode::BadPath);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [u8; 20] = [
+        0x2c, 0x00, 0x00, 0x80, 0x91, 0x03, 0x00, 0x80, 0x01, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x80, 0x02, 0x00, 0x00, 0x80,
+    ];
+
+    #[test]
+    fn parse_path() {
+        let path = parse(&DATA).unwrap();
+        assert_eq!(
+            path,
+            [0x8000002c, 0x80000391, 0x80000001, 0x80000000, 0x80000002]
+        );
+        assert_eq!(check(&path), Ok(()));
+    }
+
+    #[test]
+    fn parse_bad_len() {
+        assert_eq!(parse(&DATA[..16]), Err(ErrorCode::BadLen));
+        assert_eq!(parse(&[0; 24]), Err(ErrorCode::BadLen));
+        assert_eq!(parse(&[]), Err(ErrorCode::BadLen));
+    }
+
+    #[test]
+    fn check_foreign_path() {
+        // Polkadot
+        let path = [0x8000002c, 0x80000162, 0x80000000, 0x80000000, 0x80000000];
+        assert_eq!(check(&path), Err(ErrorCode::BadPath));
+        // Not hardened purpose
+        let path = [0x2c, 0x80000391, 0x80000000, 0x80000000, 0x80000000];
+        assert_eq!(check(&path), Err(ErrorCode::BadPath));
+    }
+}
@@ -0,0 +1,164 @@
+//! Hint for decoding the payload of the message to a Sails program.
+//!
+//! Sails programs encode the message payload as the service name, the
+//! method name and the method arguments, all SCALE-encoded. The hint
+//! describes the method, so the payload can be shown as
+//! `Service/Method(arg=value, ...)`. See `docs/apdu.md` for its format.
+
+use crate::{apdu::MAX_DATA_LEN, error::ErrorCode, hash::Blake2b256};
+use blake2::Digest;
+use core::str;
+
+/// Maximum length of the encoded hint.
+pub const MAX_HINT_LEN: usize = MAX_DATA_LEN;
+
+/// Type of the method argument.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+pub enum Type {
+    Bool = 0,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    /// UTF-8 string.
+    Str,
+    /// 32-byte program or account id.
+    ActorId,
+    /// 32-byte hash.
+    H256,
+    /// `Vec<u8>`.
+    Bytes,
+}
+
+impl TryFrom<u8> for Type {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let ty = match value {
+            0 => Type::Bool,
+            1 => Type::U8,
+            2 => Type::U16,
+            3 => Type::U32,
+            4 => Type::U64,
+            5 => Type::U128,
+            6 => Type::Str,
+            7 => Type::ActorId,
+            8 => Type::H256,
+            9 => Type::Bytes,
+            _ => return Err(ErrorCode::ParsingError),
+        };
+        Ok(ty)
+    }
+}
+
+/// Method description given by the host.
+pub struct Hint {
+    data: [u8; MAX_HINT_LEN],
+    len: usize,
+    /// Position of the first argument.
+    args: usize,
+    /// Blake2b-256 hash of the encoded hint shown to the user.
+    hash: [u8; 32],
+}
+
+impl Hint {
+    /// Parse the encoded hint.
+    pub fn parse(data: &[u8]) -> Result<Self, ErrorCode> {
+        if data.len() > MAX_HINT_LEN {
+            return Err(ErrorCode::BadLen);
+        }
+        let mut hint = Self {
+            data: [0; MAX_HINT_LEN],
+            len: data.len(),
+            args: 0,
+            hash: Blake2b256::digest(data).into(),
+        };
+        hint.data[..data.len()].copy_from_slice(data);
+
+        let (_, pos) = hint.name(0)?;
+        let (_, mut pos) = hint.name(pos)?;
+        hint.args = pos;
+        while let Some((_, _, next)) = hint.arg(pos)? {
+            pos = next;
+        }
+        Ok(hint)
+    }
+
+    pub fn service(&self) -> &str {
+        self.name(0).map(|(name, _)| name).unwrap_or_default()
+    }
+
+    pub fn method(&self) -> &str {
+        let (_, pos) = self.name(0).unwrap_or_default();
+        self.name(pos).map(|(name, _)| name).unwrap_or_default()
+    }
+
+    pub fn hash(&self) -> &[u8; 32] {
+        &self.hash
+    }
+
+    /// Position of the first argument.
+    pub fn first_arg(&self) -> usize {
+        self.args
+    }
+
+    /// Argument at the given position with the position of the next one,
+    /// or `None` if there are no more arguments.
+    pub fn arg(&self, pos: usize) -> Result<Option<(&str, Type, usize)>, ErrorCode> {
+        if pos >= self.len {
+            return Ok(None);
+        }
+        let (name, pos) = self.name(pos)?;
+        let ty = self.data[..self.len]
+            .get(pos)
+            .copied()
+            .ok_or(ErrorCode::ParsingError)?;
+        Ok(Some((name, ty.try_into()?, pos + 1)))
+    }
+
+    /// Name prefixed with its length at the given position and the
+    /// position after it.
+    fn name(&self, pos: usize) -> Result<(&str, usize), ErrorCode> {
+        let data = &self.data[..self.len];
+        let len = *data.get(pos).ok_or(ErrorCode::ParsingError)? as usize;
+        let name = data
+            .get(pos + 1..pos + 1 + len)
+            .ok_or(ErrorCode::ParsingError)?;
+        let name = str::from_utf8(name).map_err(|_| ErrorCode::ParsingError)?;
+        Ok((name, pos + 1 + len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let hint = Hint::parse(b"\x03Vft\x08Transfer\x02to\x07\x05value\x05").unwrap();
+        assert_eq!(hint.service(), "Vft");
+        assert_eq!(hint.method(), "Transfer");
+        let (name, ty, pos) = hint.arg(hint.first_arg()).unwrap().unwrap();
+        assert_eq!((name, ty), ("to", Type::ActorId));
+        let (name, ty, pos) = hint.arg(pos).unwrap().unwrap();
+        assert_eq!((name, ty), ("value", Type::U128));
+        assert!(hint.arg(pos).unwrap().is_none());
+
+        let hint = Hint::parse(b"\x03Vft\x0bTotalSupply").unwrap();
+        assert!(hint.arg(hint.first_arg()).unwrap().is_none());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        // Truncated method name
+        assert!(Hint::parse(b"\x03Vft\x08Trans").is_err());
+        // Missing argument type
+        assert!(Hint::parse(b"\x03Vft\x08Transfer\x02to").is_err());
+        // Unknown argument type
+        assert!(Hint::parse(b"\x03Vft\x08Transfer\x02to\x0a").is_err());
+        // Invalid UTF-8
+        assert!(Hint::parse(b"\x01\xff\x08Transfer").is_err());
+    }
+}
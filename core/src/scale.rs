@@ -0,0 +1,104 @@
+/// SCALE decoding error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// More data is needed to decode the value.
+    Incomplete,
+    /// Data is malformed.
+    Invalid,
+    /// Call or enum variant is not known.
+    Unknown,
+    /// Decoded value doesn't fit into the available memory.
+    Overflow,
+}
+
+/// Reader of SCALE-encoded values from the byte slice.
+#[derive(Clone)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Number of bytes read.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Number of bytes left.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < len {
+            return Err(Error::Incomplete);
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub fn u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    pub fn u128(&mut self) -> Result<u128, Error> {
+        Ok(u128::from_le_bytes(self.array()?))
+    }
+
+    pub fn bool(&mut self) -> Result<bool, Error> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    /// Read compact-encoded integer.
+    pub fn compact(&mut self) -> Result<u128, Error> {
+        let first = self.data.get(self.pos).copied().ok_or(Error::Incomplete)?;
+        let value = match first & 0b11 {
+            0b00 => (self.u8()? >> 2) as u128,
+            0b01 => (self.u16()? >> 2) as u128,
+            0b10 => (self.u32()? >> 2) as u128,
+            _ => {
+                let len = (first >> 2) as usize + 4;
+                if len > 16 {
+                    return Err(Error::Invalid);
+                }
+                let bytes = self.bytes(len + 1)?;
+                let mut value = [0; 16];
+                value[..len].copy_from_slice(&bytes[1..]);
+                u128::from_le_bytes(value)
+            }
+        };
+        Ok(value)
+    }
+
+    /// Read compact-encoded length of the collection.
+    pub fn length(&mut self) -> Result<u32, Error> {
+        self.compact()?.try_into().map_err(|_| Error::Invalid)
+    }
+}
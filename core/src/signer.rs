@@ -0,0 +1,498 @@
+use crate::{
+    decoder::Decoder,
+    device::Device,
+    error::ErrorCode,
+    fields::Fields,
+    hash::Blake2b256,
+    networks,
+    path::{self, Path},
+    transcript::LedgerTranscript,
+};
+use blake2::Digest;
+use schnorrkel::{ExpansionMode, MiniSecretKey};
+
+/// Maximum length of the message signed as is. Longer messages are signed
+/// as their Blake2b-256 hash, as Substrate does for transaction payloads.
+const MAX_MESSAGE_LEN: usize = 256;
+
+/// Maximum size of the signer, which is kept in RAM for the whole signing
+/// next to the SDK state, the APDU buffer and the signing stack. Nano S
+/// gives the app about 4 KiB of RAM, so the signer is limited to 2.75 KiB
+/// with the reduced field capacity and the CI runs the Speculos tests on
+/// Nano S; Nano S Plus and Nano X have more than 30 KiB, so 8 KiB leaves
+/// plenty of room.
+#[cfg(target_os = "nanos")]
+const BUDGET: usize = 2816;
+#[cfg(any(target_os = "nanosplus", target_os = "nanox"))]
+const BUDGET: usize = 8 * 1024;
+
+#[cfg(any(target_os = "nanos", target_os = "nanosplus", target_os = "nanox"))]
+const _: () = assert!(core::mem::size_of::<Signer>() <= BUDGET);
+
+pub struct Signer {
+    path: Path,
+    scheme: Scheme,
+    message_len: usize,
+    message: [u8; MAX_MESSAGE_LEN],
+    hasher: Blake2b256,
+    decoder: Decoder,
+    fields: Fields,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(u8)]
+pub enum Scheme {
+    #[default]
+    Ed25519 = 0,
+    Sr25519,
+}
+
+/// Warning shown to the user before the message is reviewed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Warning {
+    /// Message can't be decoded and is blind signed.
+    BlindSigning,
+    /// Transaction is signed for the network that is not known.
+    UnknownNetwork,
+}
+
+impl Default for Signer {
+    fn default() -> Self {
+        Self {
+            path: Default::default(),
+            scheme: Default::default(),
+            message_len: Default::default(),
+            message: [0; MAX_MESSAGE_LEN],
+            hasher: Default::default(),
+            decoder: Default::default(),
+            fields: Default::default(),
+        }
+    }
+}
+
+impl TryFrom<u8> for Scheme {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Scheme::Ed25519),
+            1 => Ok(Scheme::Sr25519),
+            _ => Err(ErrorCode::BadP1P2),
+        }
+    }
+}
+
+impl Scheme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scheme::Ed25519 => "Ed25519",
+            Scheme::Sr25519 => "Sr25519",
+        }
+    }
+}
+
+impl Signer {
+    pub fn clear(&mut self) {
+        self.path.fill(0);
+        self.scheme = Scheme::Ed25519;
+        self.message_len = 0;
+        self.message.fill(0);
+        Digest::reset(&mut self.hasher);
+        self.decoder.clear();
+        self.fields.clear();
+    }
+
+    pub fn set_path(&mut self, path: Path) {
+        self.path = path;
+    }
+
+    pub fn set_scheme(&mut self, scheme: Scheme) {
+        self.scheme = scheme;
+    }
+
+    pub fn check_scheme(&self, scheme: Scheme) -> Result<(), ErrorCode> {
+        if self.scheme != scheme {
+            return Err(ErrorCode::BadP1P2);
+        }
+        Ok(())
+    }
+
+    /// Set the Sails hint for decoding the message payload. It must be set
+    /// before the message is appended.
+    pub fn set_hint(&mut self, data: &[u8]) -> Result<(), ErrorCode> {
+        if self.message_len != 0 {
+            return Err(ErrorCode::BadP1P2);
+        }
+        self.decoder.set_hint(data)
+    }
+
+    pub fn append_message(&mut self, data: &[u8]) -> Result<(), ErrorCode> {
+        let message_len = self
+            .message_len
+            .checked_add(data.len())
+            .ok_or(ErrorCode::BadLen)?;
+        if message_len <= MAX_MESSAGE_LEN {
+            self.message[self.message_len..message_len].copy_from_slice(data);
+        }
+        self.message_len = message_len;
+        self.hasher.update(data);
+        self.decoder.feed(data, &mut self.fields);
+        Ok(())
+    }
+
+    /// Prepare fields to be reviewed by the user before signing and return
+    /// the warning to be shown before them, if any.
+    ///
+    /// The message decoded as a transaction is shown field by field, with a
+    /// warning if its genesis hash doesn't belong to a known network. Any
+    /// other message, or a transaction whose fields don't fit together with
+    /// the scheme and the path, is shown as its length and Blake2b-256 hash,
+    /// and only if blind signing is enabled.
+    pub fn prepare_review(&mut self, blind_signing: bool) -> Result<Option<Warning>, ErrorCode> {
+        if let Ok(payload) = self.decoder.finish(&mut self.fields) {
+            let known = networks::find(&payload.genesis_hash).is_some();
+            if self.push_key_fields().is_ok() {
+                return Ok((!known).then_some(Warning::UnknownNetwork));
+            }
+        }
+        if !blind_signing {
+            return Err(ErrorCode::BlindSigningDisabled);
+        }
+        let hash = self.hasher.clone().finalize();
+        self.fields.clear();
+        self.fields
+            .push("Length")?
+            .number(self.message_len as u128)?
+            .str(" bytes")?;
+        self.fields.push("Hash")?.str("0x")?.hex(&hash)?;
+        self.push_key_fields()?;
+        Ok(Some(Warning::BlindSigning))
+    }
+
+    /// Add the scheme and the path fields shown after the message.
+    fn push_key_fields(&mut self) -> Result<(), ErrorCode> {
+        self.fields.push("Scheme")?.str(self.scheme.name())?;
+        self.fields.push("Path")?.path(&self.path)?;
+        Ok(())
+    }
+
+    pub fn fields(&self) -> &Fields {
+        &self.fields
+    }
+
+    pub fn get_public_key(&self, device: &impl Device) -> Result<[u8; 32], ErrorCode> {
+        path::check(&self.path)?;
+        let private_key = device.derive_private_key(&self.path)?;
+        let public_key = match self.scheme {
+            Scheme::Ed25519 => device.ed25519_public_key(&private_key)?,
+            Scheme::Sr25519 => MiniSecretKey::from_bytes(&private_key)?
+                .expand(ExpansionMode::Ed25519)
+                .to_public()
+                .to_bytes(),
+        };
+
+        Ok(public_key)
+    }
+
+    pub fn sign(&self, device: &impl Device) -> Result<[u8; 64], ErrorCode> {
+        path::check(&self.path)?;
+
+        let private_key = device.derive_private_key(&self.path)?;
+        let hash;
+        let message = if self.message_len > MAX_MESSAGE_LEN {
+            hash = self.hasher.clone().finalize();
+            &hash[..]
+        } else {
+            &self.message[..self.message_len]
+        };
+
+        let signature = match self.scheme {
+            Scheme::Ed25519 => device.ed25519_sign(&private_key, message)?,
+            Scheme::Sr25519 => {
+                let pair = MiniSecretKey::from_bytes(&private_key)?
+                    .expand(ExpansionMode::Ed25519)
+                    .to_keypair();
+                let mut transcript = LedgerTranscript::new(device.rng());
+                transcript.append(message);
+                pair.sign(transcript).to_bytes()
+            }
+        };
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use ed25519_dalek::{Signature, Signer as _, SigningKey};
+    use rand_core::{impls, CryptoRng, RngCore};
+    use schnorrkel::PublicKey;
+    use std::{vec, vec::Vec};
+
+    const PATH: Path = [0x8000002c, 0x80000391, 0x80000000, 0x80000000, 0x80000000];
+
+    /// `system.remark("Hello")` on Vara mainnet.
+    const REMARK: &str = "00001448656c6c6f0000004c04000001000000\
+        fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763\
+        fe1b4c55fd4d668101126434206571a7838a8b6b93a6d1b95d607e78e6c53763";
+
+    struct TestDevice;
+
+    #[derive(Clone)]
+    struct TestRng(u64);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for TestRng {}
+
+    impl Device for TestDevice {
+        type Rng = TestRng;
+
+        fn derive_private_key(&self, path: &[u32]) -> Result<[u8; 32], ErrorCode> {
+            let mut key = [0; 32];
+            for (k, p) in key.as_chunks_mut().0.iter_mut().zip(path.iter().cycle()) {
+                *k = p.to_le_bytes();
+            }
+            Ok(key)
+        }
+
+        fn ed25519_public_key(&self, private_key: &[u8; 32]) -> Result<[u8; 32], ErrorCode> {
+            Ok(SigningKey::from_bytes(private_key)
+                .verifying_key()
+                .to_bytes())
+        }
+
+        fn ed25519_sign(
+            &self,
+            private_key: &[u8; 32],
+            message: &[u8],
+        ) -> Result<[u8; 64], ErrorCode> {
+            Ok(SigningKey::from_bytes(private_key).sign(message).to_bytes())
+        }
+
+        fn rng(&self) -> Self::Rng {
+            TestRng(42)
+        }
+    }
+
+    fn new_signer(scheme: Scheme, message: &[u8]) -> Signer {
+        let mut signer = Signer::default();
+        signer.set_scheme(scheme);
+        signer.set_path(PATH);
+        signer.append_message(message).unwrap();
+        signer
+    }
+
+    /// `utility.batch` of empty remarks with the signed extensions of
+    /// [`REMARK`].
+    fn batch(len: usize) -> Vec<u8> {
+        let mut payload = vec![0x08, 0x00, (len as u8) << 2];
+        for _ in 0..len {
+            payload.extend_from_slice(&[0x00, 0x00, 0x00]);
+        }
+        payload.extend_from_slice(&remark()[8..]);
+        payload
+    }
+
+    fn remark() -> [u8; 83] {
+        let mut payload = [0; 83];
+        hex::decode_to_slice(REMARK, &mut payload).unwrap();
+        payload
+    }
+
+    #[test]
+    fn scheme_from_u8() {
+        assert_eq!(Scheme::try_from(0), Ok(Scheme::Ed25519));
+        assert_eq!(Scheme::try_from(1), Ok(Scheme::Sr25519));
+        assert_eq!(Scheme::try_from(2), Err(ErrorCode::BadP1P2));
+    }
+
+    #[test]
+    fn check_scheme() {
+        let signer = new_signer(Scheme::Sr25519, b"");
+        assert_eq!(signer.check_scheme(Scheme::Sr25519), Ok(()));
+        assert_eq!(
+            signer.check_scheme(Scheme::Ed25519),
+            Err(ErrorCode::BadP1P2)
+        );
+    }
+
+    #[test]
+    fn append_in_chunks() {
+        let payload = remark();
+        let mut chunked = new_signer(Scheme::Ed25519, &payload[..10]);
+        chunked.append_message(&payload[10..]).unwrap();
+        let whole = new_signer(Scheme::Ed25519, &payload);
+        assert_eq!(
+            chunked.sign(&TestDevice).unwrap(),
+            whole.sign(&TestDevice).unwrap()
+        );
+    }
+
+    #[test]
+    fn clear() {
+        let mut signer = new_signer(Scheme::Sr25519, b"Hello");
+        signer.clear();
+        assert_eq!(signer.check_scheme(Scheme::Ed25519), Ok(()));
+        assert_eq!(signer.get_public_key(&TestDevice), Err(ErrorCode::BadPath));
+        signer.set_path(PATH);
+        let signature = Signature::from_bytes(&signer.sign(&TestDevice).unwrap());
+        let key = SigningKey::from_bytes(&TestDevice.derive_private_key(&PATH).unwrap());
+        assert!(key.verify(b"", &signature).is_ok());
+    }
+
+    #[test]
+    fn hint_before_message() {
+        let mut signer = new_signer(Scheme::Ed25519, b"");
+        assert_eq!(signer.set_hint(b"\x03Vft\x08Transfer"), Ok(()));
+        assert_eq!(signer.set_hint(b"\x03Vft"), Err(ErrorCode::ParsingError));
+        signer.append_message(b"Hello").unwrap();
+        assert_eq!(
+            signer.set_hint(b"\x03Vft\x08Transfer"),
+            Err(ErrorCode::BadP1P2)
+        );
+    }
+
+    #[test]
+    fn bad_path() {
+        let mut signer = new_signer(Scheme::Ed25519, b"Hello");
+        signer.set_path([0x8000002c, 0x80000162, 0x80000000, 0x80000000, 0x80000000]);
+        assert_eq!(signer.get_public_key(&TestDevice), Err(ErrorCode::BadPath));
+        assert_eq!(signer.sign(&TestDevice), Err(ErrorCode::BadPath));
+    }
+
+    #[test]
+    fn review_decoded() {
+        let mut signer = new_signer(Scheme::Ed25519, &remark());
+        assert_eq!(signer.prepare_review(false), Ok(None));
+        assert_eq!(signer.fields().label(0), "Call");
+        assert_eq!(signer.fields().value(0), "system.remark");
+        assert_eq!(signer.fields().value(1), "0x48656c6c6f");
+    }
+
+    #[test]
+    fn review_full_fields() {
+        // Call, 2 fields per remark, 4 signed extensions, scheme and path
+        let len = (crate::fields::MAX_FIELDS - 7) / 2;
+        let mut signer = new_signer(Scheme::Ed25519, &batch(len));
+        assert_eq!(signer.prepare_review(false), Ok(None));
+        assert_eq!(signer.fields().label(signer.fields().len() - 1), "Path");
+
+        // Scheme and path don't fit, so the transaction is blind signed
+        let mut signer = new_signer(Scheme::Ed25519, &batch(len + 1));
+        assert_eq!(
+            signer.prepare_review(false),
+            Err(ErrorCode::BlindSigningDisabled)
+        );
+        let mut signer = new_signer(Scheme::Ed25519, &batch(len + 1));
+        assert_eq!(signer.prepare_review(true), Ok(Some(Warning::BlindSigning)));
+        assert_eq!(signer.fields().len(), 4);
+    }
+
+    #[test]
+    fn review_fits_nanos() {
+        // `gear.upload_program` with 40-byte code
+        let mut payload = [&[0x68, 0x01, 0xa0][..], &[0; 40], &[0, 0]].concat();
+        payload.extend_from_slice(&1000u64.to_le_bytes());
+        payload.extend_from_slice(&[0; 17]);
+        payload.extend_from_slice(&remark()[8..]);
+        let mut signer = new_signer(Scheme::Ed25519, &payload);
+        assert_eq!(signer.prepare_review(false), Ok(None));
+
+        let fields = signer.fields();
+        assert_eq!(fields.value(0), "gear.upload_program");
+        assert!(fields.len() <= crate::fields::NANOS_MAX_FIELDS);
+        let text_len: usize = (0..fields.len())
+            .map(|i| fields.label(i).len() + fields.value(i).len())
+            .sum();
+        assert!(text_len <= crate::fields::NANOS_TEXT_LEN);
+    }
+
+    #[test]
+    fn review_unknown_network() {
+        let mut payload = remark();
+        // Genesis hash followed by the block hash
+        payload[19..51].fill(0);
+        let mut signer = new_signer(Scheme::Ed25519, &payload);
+        assert_eq!(
+            signer.prepare_review(false),
+            Ok(Some(Warning::UnknownNetwork))
+        );
+        assert_eq!(signer.fields().label(2), "Network");
+        assert_eq!(signer.fields().value(2), "Unknown");
+    }
+
+    #[test]
+    fn review_blind() {
+        let mut signer = new_signer(Scheme::Ed25519, b"Hello");
+        assert_eq!(
+            signer.prepare_review(false),
+            Err(ErrorCode::BlindSigningDisabled)
+        );
+
+        let mut signer = new_signer(Scheme::Ed25519, b"Hello");
+        assert_eq!(signer.prepare_review(true), Ok(Some(Warning::BlindSigning)));
+        let fields = signer.fields();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields.value(0), "5 bytes");
+        assert_eq!(fields.label(1), "Hash");
+        assert_eq!(fields.value(2), "Ed25519");
+        assert_eq!(fields.value(3), "44'/913'/0'/0'/0'");
+    }
+
+    #[test]
+    fn sign_ed25519() {
+        let signer = new_signer(Scheme::Ed25519, b"Hello");
+        let public_key = signer.get_public_key(&TestDevice).unwrap();
+        let signature = Signature::from_bytes(&signer.sign(&TestDevice).unwrap());
+        let key = SigningKey::from_bytes(&TestDevice.derive_private_key(&PATH).unwrap());
+        assert_eq!(public_key, key.verifying_key().to_bytes());
+        assert!(key.verify(b"Hello", &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_sr25519() {
+        let payload = remark();
+        let signer = new_signer(Scheme::Sr25519, &payload);
+        let public_key = PublicKey::from_bytes(&signer.get_public_key(&TestDevice).unwrap());
+        let signature =
+            schnorrkel::Signature::from_bytes(&signer.sign(&TestDevice).unwrap()).unwrap();
+        assert!(public_key
+            .unwrap()
+            .verify_simple(b"", &payload, &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn sign_long_message_hash() {
+        let message = [7; 300];
+        let signer = new_signer(Scheme::Sr25519, &message);
+        let public_key = PublicKey::from_bytes(&signer.get_public_key(&TestDevice).unwrap());
+        let signature =
+            schnorrkel::Signature::from_bytes(&signer.sign(&TestDevice).unwrap()).unwrap();
+        let hash = Blake2b256::digest(message);
+        let public_key = public_key.unwrap();
+        assert!(public_key.verify_simple(b"", &hash, &signature).is_ok());
+        assert!(public_key.verify_simple(b"", &message, &signature).is_err());
+    }
+}
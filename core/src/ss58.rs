@@ -0,0 +1,51 @@
+use blake2::{Blake2b512, Digest};
+use core::str;
+
+/// SS58 address prefix of the Vara network.
+pub const VARA_PREFIX: u16 = 137;
+
+/// Maximum length of the SS58 address with a two-byte prefix.
+const MAX_ADDRESS_LEN: usize = 50;
+
+/// SS58-encoded address.
+pub struct Address {
+    buf: [u8; MAX_ADDRESS_LEN],
+    len: usize,
+}
+
+impl Address {
+    /// Encode the public key as the Vara address.
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self::with_prefix(key, VARA_PREFIX)
+    }
+
+    /// Encode the public key as the address with the given network prefix.
+    pub fn with_prefix(key: &[u8; 32], prefix: u16) -> Self {
+        let mut data = [0; 36];
+        let prefix_len = if prefix < 64 {
+            data[0] = prefix as u8;
+            1
+        } else {
+            data[0] = ((prefix & 0b0000_0000_1111_1100) >> 2) as u8 | 0b0100_0000;
+            data[1] = (prefix >> 8) as u8 | ((prefix & 0b0000_0000_0000_0011) << 6) as u8;
+            2
+        };
+        data[prefix_len..prefix_len + 32].copy_from_slice(key);
+
+        let mut hasher = Blake2b512::new();
+        hasher.update(b"SS58PRE");
+        hasher.update(&data[..prefix_len + 32]);
+        let checksum = hasher.finalize();
+        data[prefix_len + 32..prefix_len + 34].copy_from_slice(&checksum[..2]);
+
+        let mut buf = [0; MAX_ADDRESS_LEN];
+        let len = bs58::encode(&data[..prefix_len + 34])
+            .onto(&mut buf[..])
+            .unwrap_or_default();
+        Self { buf, len }
+    }
+
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
@@ -0,0 +1,49 @@
+use merlin::Transcript;
+use rand_core::{CryptoRng, RngCore};
+use schnorrkel::context::SigningTranscript;
+
+/// Signing transcript with the empty context that uses the given RNG for
+/// the witness.
+pub struct LedgerTranscript<R> {
+    transcript: Transcript,
+    rng: R,
+}
+
+impl<R> SigningTranscript for LedgerTranscript<R>
+where
+    R: CryptoRng + RngCore + Clone,
+{
+    fn commit_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.transcript.append_message(label, bytes);
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.transcript.challenge_bytes(label, dest);
+    }
+
+    fn witness_bytes_rng<T>(
+        &self,
+        label: &'static [u8],
+        dest: &mut [u8],
+        nonce_seeds: &[&[u8]],
+        _rng: T,
+    ) where
+        T: CryptoRng + RngCore,
+    {
+        self.transcript
+            .witness_bytes_rng(label, dest, nonce_seeds, self.rng.clone());
+    }
+}
+
+impl<R> LedgerTranscript<R> {
+    pub fn new(rng: R) -> Self {
+        let mut transcript = Transcript::new(b"SigningContext");
+        // Append empty context
+        transcript.append_message(b"", b"");
+        Self { transcript, rng }
+    }
+
+    pub fn append(&mut self, bytes: &[u8]) {
+        self.transcript.append_message(b"sign-bytes", bytes);
+    }
+}